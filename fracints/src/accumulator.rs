@@ -0,0 +1,142 @@
+use crate::{Fracint, FracintDouble, FracintHalf};
+
+/// An exact accumulator of sums and products of fracints. `Sum` for fracints
+/// saturates after every element, so an intermediate overflow clamps the
+/// result even if the final result would be in range. The accumulator instead
+/// keeps the sum in the double width `F::Double` along with guard bits
+/// counting how many times it has wrapped around, which makes every
+/// operation exact until the final `round` or `saturate`.
+///
+/// # Examples
+///
+/// ```
+/// use fracints::*;
+///
+/// let mut acc = Accumulator::<fi16>::new();
+/// acc.add(fi16!(0.75));
+/// acc.add(fi16!(0.75));
+/// acc.add(fi16!(-0.75));
+/// assert_eq!(acc.round(), Some(fi16!(0.75)));
+/// acc.add_product(fi16!(0.5), fi16!(0.5));
+/// assert_eq!(acc.round(), None);
+/// assert_eq!(acc.saturate(), fi16::ONE);
+///
+/// // `Sum` clamps the intermediate result
+/// let x = [fi16!(0.75), fi16!(0.75), fi16!(-0.75)];
+/// assert_eq!(x.iter().copied().sum::<fi16>(), fi16::ONE - fi16!(0.75));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Accumulator<F: FracintDouble> {
+    /// The low part that wraps
+    lo: F::Double,
+    /// The number of times `lo` has wrapped around, the numerical value of
+    /// the accumulator is `lo + hi * 2` for signed fracints and `lo + hi` for
+    /// unsigned fracints
+    hi: i64,
+}
+
+impl<F: FracintDouble> Accumulator<F> {
+    /// Creates a zeroed accumulator
+    pub const fn new() -> Self {
+        Self {
+            lo: F::Double::ZERO,
+            hi: 0,
+        }
+    }
+
+    /// Adds a double width value exactly
+    pub fn add_double(&mut self, x: F::Double) {
+        let res = self.lo.wrapping_add(x);
+        if x.is_negative() {
+            if res > self.lo {
+                self.hi -= 1;
+            }
+        } else if res < self.lo {
+            self.hi += 1;
+        }
+        self.lo = res;
+    }
+
+    /// Adds `x` exactly
+    pub fn add(&mut self, x: F) {
+        self.add_double(x.widen());
+    }
+
+    /// Adds the product `lhs * rhs` exactly
+    pub fn add_product(&mut self, lhs: F, rhs: F) {
+        if F::SIGNED && (lhs == F::MIN) && (rhs == F::MIN) {
+            // the exact product is one ULP above what `saturating_widening_mul` returns
+            self.add_double(F::Double::ONE);
+            self.add_double(F::Double::ULP);
+        } else {
+            self.add_double(lhs.saturating_widening_mul(rhs));
+        }
+    }
+
+    /// Rounds to the nearest `F`, returning the number of wraps along with the
+    /// rounded low part
+    fn round_parts(&self) -> (i64, F) {
+        let (low, high) = self.lo.split();
+        let round_bit = F::ULP << (F::BITS - 1);
+        let odd = !(high & F::ULP).is_zero();
+        let sticky = !(low & !round_bit).is_zero();
+        if !(low & round_bit).is_zero() && (odd || sticky) {
+            let res = high.wrapping_add(F::ULP);
+            if res < high {
+                (self.hi + 1, res)
+            } else {
+                (self.hi, res)
+            }
+        } else {
+            (self.hi, high)
+        }
+    }
+
+    /// Rounds the accumulated value to the nearest `F`, with exact ties
+    /// rounding to even. Returns `None` if the rounded value is outside of the
+    /// numeric bounds `F::NEG_ONE` and `F::ONE`.
+    pub fn round(&self) -> Option<F> {
+        match self.round_parts() {
+            (0, res) if !(F::SIGNED && (res == F::MIN)) => Some(res),
+            _ => None,
+        }
+    }
+
+    /// Rounds the accumulated value like `round`, except that it saturates at
+    /// the numeric bounds `F::NEG_ONE` and `F::ONE` instead of returning
+    /// `None`
+    pub fn saturate(&self) -> F {
+        match self.round_parts() {
+            (0, res) if !(F::SIGNED && (res == F::MIN)) => res,
+            (hi, _) if hi > 0 => F::ONE,
+            _ => F::NEG_ONE,
+        }
+    }
+}
+
+/// Calculates the dot product of `lhs` and `rhs` with an [Accumulator], so the
+/// result is exact before being rounded to the nearest `F` and saturated at
+/// the numeric bounds `F::NEG_ONE` and `F::ONE`
+///
+/// # Panics
+///
+/// This function will panic if `lhs` and `rhs` have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use fracints::*;
+///
+/// let x = [fi32!(0.75), fi32!(0.75), fi32!(0.5)];
+/// let y = [fi32!(0.75), fi32!(0.75), fi32!(-0.5)];
+/// // the intermediate sum of 1.125 is not clamped
+/// assert_eq!(dot(&x, &y), fi32!(0.875));
+/// ```
+pub fn dot<F: FracintDouble>(lhs: &[F], rhs: &[F]) -> F {
+    assert_eq!(lhs.len(), rhs.len());
+    let mut acc = Accumulator::new();
+    for (lhs, rhs) in lhs.iter().zip(rhs) {
+        acc.add_product(*lhs, *rhs);
+    }
+    acc.saturate()
+}
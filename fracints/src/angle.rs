@@ -0,0 +1,172 @@
+use core::{
+    f64::consts::PI,
+    fmt,
+    ops::{Add, AddAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{FracintSigned, impl_signed::*};
+
+/// An angle in half turns. The wrapping arithmetic of `fiN` types matches
+/// the wrapping of angles around the circle, so `Add`, `Sub`, and `Neg` wrap
+/// instead of saturating like they do for `fiN`. `fiN::MIN` is a legitimate
+/// value representing -180 degrees, and there is one more ULP of range below
+/// zero than above, so 180 degrees is always represented as -180 degrees.
+///
+/// The unsigned `fuN` types cannot represent the negative half of the circle,
+/// so this is bounded on [FracintSigned].
+///
+/// # Examples
+///
+/// ```
+/// use fracints::prelude::*;
+///
+/// let a = Angle::from_degrees(170.0);
+/// let b: Angle<fi32> = a + Angle::from_degrees(20.0);
+/// assert!((b.to_degrees() + 170.0).abs() < 1e-6);
+/// assert_eq!(Angle(fi32::MIN).to_degrees(), -180.0);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Angle<F: FracintSigned>(pub F);
+
+impl<F: FracintSigned> Angle<F> {
+    /// The -180 degree angle
+    pub const HALF_TURN: Self = Self(F::MIN);
+    /// Zero angle
+    pub const ZERO: Self = Self(F::ZERO);
+
+    /// Creates an angle from `half_turns`
+    pub const fn new(half_turns: F) -> Self {
+        Self(half_turns)
+    }
+
+    /// Returns the angle in half turns
+    pub const fn half_turns(self) -> F {
+        self.0
+    }
+
+    /// Creates an angle from a number of half turns in a `f64`, wrapping it
+    /// to the range `[-1, 1)`. Returns `None` if `half_turns` is not finite.
+    pub fn from_half_turns_f64(half_turns: f64) -> Option<Self> {
+        if !half_turns.is_finite() {
+            return None
+        }
+        let mut h = half_turns % 2.0;
+        if h >= 1.0 {
+            h -= 2.0;
+        } else if h < -1.0 {
+            h += 2.0;
+        }
+        if h == -1.0 {
+            // `from_f64` would return `NEG_ONE`
+            return Some(Self(F::MIN))
+        }
+        F::from_f64(h).map(Self)
+    }
+
+    /// Creates an angle from degrees, wrapping to the range `[-180, 180)`.
+    /// Rounding is the same as `Fracint::from_f64`.
+    ///
+    /// # Panics
+    ///
+    /// If `degrees` is not finite
+    pub fn from_degrees(degrees: f64) -> Self {
+        Self::from_half_turns_f64(degrees / 180.0).unwrap()
+    }
+
+    /// Creates an angle from radians, wrapping to the range `[-pi, pi)`.
+    /// Rounding is the same as `Fracint::from_f64`.
+    ///
+    /// # Panics
+    ///
+    /// If `radians` is not finite
+    pub fn from_radians(radians: f64) -> Self {
+        Self::from_half_turns_f64(radians / PI).unwrap()
+    }
+
+    /// Returns the angle in degrees in the range `[-180, 180)`
+    pub fn to_degrees(self) -> f64 {
+        self.0.to_f64() * 180.0
+    }
+
+    /// Returns the angle in radians in the range `[-pi, pi)`
+    pub fn to_radians(self) -> f64 {
+        self.0.to_f64() * PI
+    }
+
+    /// Returns the shortest angular distance between `self` and `rhs` in half
+    /// turns, in the range `[0, 1]`. Angles on opposite sides of the circle
+    /// have a distance of `ONE`.
+    pub fn distance(self, rhs: Self) -> F {
+        self.0.wrapping_sub(rhs.0).saturating_abs()
+    }
+}
+
+impl<F: FracintSigned> From<F> for Angle<F> {
+    fn from(half_turns: F) -> Self {
+        Self(half_turns)
+    }
+}
+
+impl<F: FracintSigned> Add for Angle<F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0.wrapping_add(rhs.0))
+    }
+}
+
+impl<F: FracintSigned> AddAssign for Angle<F> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<F: FracintSigned> Sub for Angle<F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0.wrapping_sub(rhs.0))
+    }
+}
+
+impl<F: FracintSigned> SubAssign for Angle<F> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<F: FracintSigned> Neg for Angle<F> {
+    type Output = Self;
+
+    /// Note that the negation of -180 degrees is itself
+    fn neg(self) -> Self {
+        Self(self.0.wrapping_neg())
+    }
+}
+
+/// Displays the angle in degrees followed by a degree sign, the precision is
+/// passed through to the `f64` formatting
+impl<F: FracintSigned> fmt::Display for Angle<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(precision) = f.precision() {
+            write!(f, "{:.*}°", precision, self.to_degrees())
+        } else {
+            write!(f, "{}°", self.to_degrees())
+        }
+    }
+}
+
+macro_rules! impl_angle {
+    ($($ty:ident)*) => {$(
+        impl Angle<$ty> {
+            /// Returns `(cos(self), sin(self))` using
+            #[doc = concat!("[`", stringify!($ty), "::cos_sin_pi_taylor`]")]
+            pub fn cos_sin(self) -> ($ty, $ty) {
+                self.0.cos_sin_pi_taylor()
+            }
+        }
+    )*};
+}
+
+impl_angle!(fi8 fi16 fi32 fi64 fi128);
@@ -6,7 +6,7 @@ use crate::Fracint;
 //#![cfg_attr(feature = "unstable", feature(generic_const_exprs))]
 //#![cfg_attr(feature = "unstable", allow(incomplete_features))]
 
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Awfi<const N: usize, const LEN: usize>(InlAwi<N, LEN>);
 
@@ -1,77 +1,1519 @@
 // The generating function is in the testcrate of the repo containing this
 // crate.
+use fracints_internals::i256;
+
 use crate::impl_signed::*;
 
 pub struct Const8 {
-    pub num_4divtau: fi8,
-    pub num_4divtau_sqr: fi8,
+    pub num_4divtau: fi16,
+    pub num_4divtau_sqr: fi16,
     pub cos_taylor_iters: usize,
     pub sin_taylor_iters: usize,
+    pub exp_taylor_iters: usize,
+    pub ln_taylor_iters: usize,
 }
 
 pub const CONST8: Const8 = Const8 {
-    num_4divtau: fi8(81),
-    num_4divtau_sqr: fi8(52),
-    cos_taylor_iters: 0,
-    sin_taylor_iters: 0,
+    num_4divtau: fi16(20861),
+    num_4divtau_sqr: fi16(13280),
+    cos_taylor_iters: 2,
+    sin_taylor_iters: 3,
+    exp_taylor_iters: 6,
+    ln_taylor_iters: 5,
 };
 
 pub struct Const16 {
-    pub num_4divtau: fi16,
-    pub num_4divtau_sqr: fi16,
+    pub num_4divtau: fi32,
+    pub num_4divtau_sqr: fi32,
     pub cos_taylor_iters: usize,
     pub sin_taylor_iters: usize,
+    pub exp_taylor_iters: usize,
+    pub ln_taylor_iters: usize,
 }
 
 pub const CONST16: Const16 = Const16 {
-    num_4divtau: fi16(81),
-    num_4divtau_sqr: fi16(52),
-    cos_taylor_iters: 0,
-    sin_taylor_iters: 0,
+    num_4divtau: fi32(1367130551),
+    num_4divtau_sqr: fi32(870342340),
+    cos_taylor_iters: 4,
+    sin_taylor_iters: 5,
+    exp_taylor_iters: 11,
+    ln_taylor_iters: 12,
 };
 
 pub struct Const32 {
-    pub num_4divtau: fi32,
-    pub num_4divtau_sqr: fi32,
+    pub num_4divtau: fi64,
+    pub num_4divtau_sqr: fi64,
     pub cos_taylor_iters: usize,
     pub sin_taylor_iters: usize,
+    pub exp_taylor_iters: usize,
+    pub ln_taylor_iters: usize,
 }
 
 pub const CONST32: Const32 = Const32 {
-    num_4divtau: fi32(81),
-    num_4divtau_sqr: fi32(52),
-    cos_taylor_iters: 0,
-    sin_taylor_iters: 0,
+    num_4divtau: fi64(5871781006564002453),
+    num_4divtau_sqr: fi64(3738091887791062894),
+    cos_taylor_iters: 8,
+    sin_taylor_iters: 8,
+    exp_taylor_iters: 19,
+    ln_taylor_iters: 28,
 };
 
 pub struct Const64 {
-    pub num_4divtau: fi64,
-    pub num_4divtau_sqr: fi64,
+    pub num_4divtau: fi128,
+    pub num_4divtau_sqr: fi128,
     pub cos_taylor_iters: usize,
     pub sin_taylor_iters: usize,
+    pub exp_taylor_iters: usize,
+    pub ln_taylor_iters: usize,
 }
 
 pub const CONST64: Const64 = Const64 {
-    num_4divtau: fi64(81),
-    num_4divtau_sqr: fi64(52),
-    cos_taylor_iters: 0,
-    sin_taylor_iters: 0,
+    num_4divtau: fi128(108315241484954818046902227470560947936),
+    num_4divtau_sqr: fi128(68955624378091539635910985229956156871),
+    cos_taylor_iters: 14,
+    sin_taylor_iters: 15,
+    exp_taylor_iters: 32,
+    ln_taylor_iters: 59,
 };
 
 pub struct Const128 {
-    pub num_4divtau: fi128,
-    pub num_4divtau_sqr: fi128,
+    pub num_4divtau: fi256,
+    pub num_4divtau_sqr: fi256,
     pub cos_taylor_iters: usize,
     pub sin_taylor_iters: usize,
+    pub exp_taylor_iters: usize,
+    pub ln_taylor_iters: usize,
 }
 
 pub const CONST128: Const128 = Const128 {
-    num_4divtau: fi128(81),
-    num_4divtau_sqr: fi128(52),
-    cos_taylor_iters: 0,
-    sin_taylor_iters: 0,
+    num_4divtau: fi256(i256::from_parts(
+        145806405194311788956099437520561758897,
+        108315241484954818046902227470560947936,
+    )),
+    num_4divtau_sqr: fi256(i256::from_parts(
+        14320425888995214347017790135628411366,
+        68955624378091539635910985229956156871,
+    )),
+    cos_taylor_iters: 25,
+    sin_taylor_iters: 26,
+    exp_taylor_iters: 56,
+    ln_taylor_iters: 123,
 };
 
+pub const CORDIC_ATAN8: [fi16; 10] = [
+    fi16(8192),
+    fi16(4836),
+    fi16(2555),
+    fi16(1297),
+    fi16(651),
+    fi16(326),
+    fi16(163),
+    fi16(81),
+    fi16(41),
+    fi16(20),
+];
+pub const CORDIC_GAIN8: fi16 = fi16(19898);
+
+pub const CORDIC_ATAN16: [fi32; 18] = [
+    fi32(536870912),
+    fi32(316933406),
+    fi32(167458907),
+    fi32(85004756),
+    fi32(42667331),
+    fi32(21354465),
+    fi32(10679838),
+    fi32(5340245),
+    fi32(2670163),
+    fi32(1335087),
+    fi32(667544),
+    fi32(333772),
+    fi32(166886),
+    fi32(83443),
+    fi32(41722),
+    fi32(20861),
+    fi32(10430),
+    fi32(5215),
+];
+pub const CORDIC_GAIN16: fi32 = fi32(1304065748);
+
+pub const CORDIC_ATAN32: [fi64; 34] = [
+    fi64(2305843009213693952),
+    fi64(1361218612134873190),
+    fi64(719230530580881038),
+    fi64(365092647525521947),
+    fi64(183254791493294829),
+    fi64(91716730292036216),
+    fi64(45869556482713130),
+    fi64(22936177926750895),
+    fi64(11468263948075831),
+    fi64(5734153847876408),
+    fi64(2867079658191483),
+    fi64(1433540170878135),
+    fi64(716770128161890),
+    fi64(358385069421298),
+    fi64(179192535378193),
+    fi64(89596267772540),
+    fi64(44798133896700),
+    fi64(22399066949654),
+    fi64(11199533474990),
+    fi64(5599766737515),
+    fi64(2799883368760),
+    fi64(1399941684380),
+    fi64(699970842190),
+    fi64(349985421095),
+    fi64(174992710548),
+    fi64(87496355274),
+    fi64(43748177637),
+    fi64(21874088818),
+    fi64(10937044409),
+    fi64(5468522205),
+    fi64(2734261102),
+    fi64(1367130551),
+    fi64(683565276),
+    fi64(341782638),
+];
+pub const CORDIC_GAIN32: fi64 = fi64(5600919740058905908);
+
+pub const CORDIC_ATAN64: [fi128; 66] = [
+    fi128(42535295865117307932921825928971026432),
+    fi128(25110051366422112766444184455991104944),
+    fi128(13267461527623843717736015602466808018),
+    fi128(6734770632096352179287626750042712426),
+    fi128(3380454238957815934986843870912224701),
+    fi128(1691875050974636387033325084021028666),
+    fi128(846143969211173966839595239478680137),
+    fi128(423097804243839895882966211458931487),
+    fi128(211552130019904740223589520297966763),
+    fi128(105776468511052945938264908828174402),
+    fi128(52888284693596953424677232919037955),
+    fi128(26444148651570815304339005142117330),
+    fi128(13222075113882372744024039807668701),
+    fi128(6611037655453320219998148826007453),
+    fi128(3305518840040677253856919481979873),
+    fi128(1652759421559590782812552757626001),
+    fi128(826379710972201911294971209586001),
+    fi128(413189855510151770646171933788049),
+    fi128(206594927758082237198315491617052),
+    fi128(103297463879416912583573740573864),
+    fi128(51648731939755430539839254138067),
+    fi128(25824365969883587050926187066221),
+    fi128(12912182984942527498088913908253),
+    fi128(6456091492471355495622684512753),
+    fi128(3228045746235689216133620701572),
+    fi128(1614022873117846041607095156447),
+    fi128(807011436558923199996083178931),
+    fi128(403505718279461622397108539554),
+    fi128(201752859139730813998437638538),
+    fi128(100876429569865407349204240364),
+    fi128(50438214784932703718350297819),
+    fi128(25219107392466351864643671114),
+    fi128(12609553696233175933005400833),
+    fi128(6304776848116587966588146076),
+    fi128(3152388424058293983304753745),
+    fi128(1576194212029146991653711961),
+    fi128(788097106014573495827022867),
+    fi128(394048553007286747913532294),
+    fi128(197024276503643373956768755),
+    fi128(98512138251821686978384703),
+    fi128(49256069125910843489192392),
+    fi128(24628034562955421744596201),
+    fi128(12314017281477710872298101),
+    fi128(6157008640738855436149051),
+    fi128(3078504320369427718074525),
+    fi128(1539252160184713859037263),
+    fi128(769626080092356929518631),
+    fi128(384813040046178464759316),
+    fi128(192406520023089232379658),
+    fi128(96203260011544616189829),
+    fi128(48101630005772308094914),
+    fi128(24050815002886154047457),
+    fi128(12025407501443077023729),
+    fi128(6012703750721538511864),
+    fi128(3006351875360769255932),
+    fi128(1503175937680384627966),
+    fi128(751587968840192313983),
+    fi128(375793984420096156992),
+    fi128(187896992210048078496),
+    fi128(93948496105024039248),
+    fi128(46974248052512019624),
+    fi128(23487124026256009812),
+    fi128(11743562013128004906),
+    fi128(5871781006564002453),
+    fi128(2935890503282001226),
+    fi128(1467945251641000613),
+];
+pub const CORDIC_GAIN64: fi128 = fi128(103318733022254464881547511815561191541);
+
+pub const CORDIC_ATAN128: [fi256; 130] = [
+    fi256(i256::from_parts(0, 42535295865117307932921825928971026432)),
+    fi256(i256::from_parts(
+        268532008149338596224531758322371843807,
+        25110051366422112766444184455991104943,
+    )),
+    fi256(i256::from_parts(
+        298906518353915024761003289571416320309,
+        13267461527623843717736015602466808017,
+    )),
+    fi256(i256::from_parts(
+        135675867037456121975959111863859278900,
+        6734770632096352179287626750042712426,
+    )),
+    fi256(i256::from_parts(
+        321428013503117349040427830976593995486,
+        3380454238957815934986843870912224700,
+    )),
+    fi256(i256::from_parts(
+        80662286220460077989489708663152595765,
+        1691875050974636387033325084021028666,
+    )),
+    fi256(i256::from_parts(
+        325658862974635759147451431534559635443,
+        846143969211173966839595239478680136,
+    )),
+    fi256(i256::from_parts(
+        195476697624415517654320672612027407394,
+        423097804243839895882966211458931486,
+    )),
+    fi256(i256::from_parts(
+        168118594910105085114401050222907839459,
+        211552130019904740223589520297966763,
+    )),
+    fi256(i256::from_parts(
+        132094294492868008997903127643607737521,
+        105776468511052945938264908828174402,
+    )),
+    fi256(i256::from_parts(
+        181992475093706839623653690347026764235,
+        52888284693596953424677232919037954,
+    )),
+    fi256(i256::from_parts(
+        118677804561599317020478425345296864726,
+        26444148651570815304339005142117330,
+    )),
+    fi256(i256::from_parts(
+        322906086290389739954392878270602012223,
+        13222075113882372744024039807668700,
+    )),
+    fi256(i256::from_parts(
+        143631288153891092426485100565773170689,
+        6611037655453320219998148826007453,
+    )),
+    fi256(i256::from_parts(
+        244052588743164635207146719061766475528,
+        3305518840040677253856919481979872,
+    )),
+    fi256(i256::from_parts(
+        301178801238457428952455528079728705144,
+        1652759421559590782812552757626000,
+    )),
+    fi256(i256::from_parts(
+        211446646524775003285678443999469111393,
+        826379710972201911294971209586000,
+    )),
+    fi256(i256::from_parts(
+        112447385701904972040849018153508083090,
+        413189855510151770646171933788049,
+    )),
+    fi256(i256::from_parts(
+        297278527852208764925996714333624623370,
+        206594927758082237198315491617051,
+    )),
+    fi256(i256::from_parts(
+        14358146102001374531031752475152048478,
+        103297463879416912583573740573864,
+    )),
+    fi256(i256::from_parts(
+        269084233585894296492567211804187243550,
+        51648731939755430539839254138066,
+    )),
+    fi256(i256::from_parts(
+        34468387664801722312527618655338810691,
+        25824365969883587050926187066221,
+    )),
+    fi256(i256::from_parts(
+        215909868779576886739150884194618656906,
+        12912182984942527498088913908252,
+    )),
+    fi256(i256::from_parts(
+        86219804326729504005622185868620749480,
+        6456091492471355495622684512753,
+    )),
+    fi256(i256::from_parts(
+        233669612638188269753657184526747258393,
+        3228045746235689216133620701571,
+    )),
+    fi256(i256::from_parts(
+        233094483658769514527254888527055000537,
+        1614022873117846041607095156446,
+    )),
+    fi256(i256::from_parts(
+        104060812639507366399966172746812161185,
+        807011436558923199996083178931,
+    )),
+    fi256(i256::from_parts(
+        54277560379474448993225090323769574195,
+        403505718279461622397108539554,
+    )),
+    fi256(i256::from_parts(
+        48806321058150206750643426449802353346,
+        201752859139730813998437638538,
+    )),
+    fi256(i256::from_parts(
+        69650617711443697608184693062384337201,
+        100876429569865407349204240364,
+    )),
+    fi256(i256::from_parts(
+        338228428268985943098600889254342217811,
+        50438214784932703718350297818,
+    )),
+    fi256(i256::from_parts(
+        36898424232233216119581365710850503621,
+        25219107392466351864643671114,
+    )),
+    fi256(i256::from_parts(
+        214598717817406661561783510942558299428,
+        12609553696233175933005400832,
+    )),
+    fi256(i256::from_parts(
+        259423934720262948244471917499584765439,
+        6304776848116587966588146075,
+    )),
+    fi256(i256::from_parts(
+        106192243471569944527794952472204608762,
+        3152388424058293983304753745,
+    )),
+    fi256(i256::from_parts(
+        28888508317159590415491853093101109258,
+        1576194212029146991653711961,
+    )),
+    fi256(i256::from_parts(
+        202827133874279616403510953321738237397,
+        788097106014573495827022866,
+    )),
+    fi256(i256::from_parts(
+        18623187238809019401067399966205425885,
+        394048553007286747913532294,
+    )),
+    fi256(i256::from_parts(
+        211639275482699700870748878159276927655,
+        197024276503643373956768754,
+    )),
+    fi256(i256::from_parts(
+        88575302109144441402032387238881465614,
+        98512138251821686978384703,
+    )),
+    fi256(i256::from_parts(
+        127202700830781160437795303897794168750,
+        49256069125910843489192392,
+    )),
+    fi256(i256::from_parts(
+        95233379569975351652459179163817589829,
+        24628034562955421744596201,
+    )),
+    fi256(i256::from_parts(
+        94105989294428080188346707208546159927,
+        12314017281477710872298101,
+    )),
+    fi256(i256::from_parts(
+        244272988478921976337586213137841159390,
+        6157008640738855436149050,
+    )),
+    fi256(i256::from_parts(
+        125521345535865826232758801144564476090,
+        3078504320369427718074525,
+    )),
+    fi256(i256::from_parts(
+        233324962640452749606062416113197874563,
+        1539252160184713859037262,
+    )),
+    fi256(i256::from_parts(
+        116715369621732700397780672034824004997,
+        769626080092356929518631,
+    )),
+    fi256(i256::from_parts(
+        228505479309023872629921322730577245640,
+        384813040046178464759315,
+    )),
+    fi256(i256::from_parts(
+        284394749494692204384065925455832964598,
+        192406520023089232379657,
+    )),
+    fi256(i256::from_parts(
+        312338661505279213465897511490633120467,
+        96203260011544616189828,
+    )),
+    fi256(i256::from_parts(
+        156169343664822591675720911376170626880,
+        48101630005772308094914,
+    )),
+    fi256(i256::from_parts(
+        78084673446434168955706975141942071774,
+        24050815002886154047457,
+    )),
+    fi256(i256::from_parts(
+        209183520385439175349271606218587236407,
+        12025407501443077023728,
+    )),
+    fi256(i256::from_parts(
+        104591760217938695067102154975760130052,
+        6012703750721538511864,
+    )),
+    fi256(i256::from_parts(
+        52295880112121735957609371471188379007,
+        3006351875360769255932,
+    )),
+    fi256(i256::from_parts(
+        26147940056454916531811972483507728751,
+        1503175937680384627966,
+    )),
+    fi256(i256::from_parts(
+        13073970028276714335031897085243056782,
+        751587968840192313983,
+    )),
+    fi256(i256::from_parts(
+        176678168474613745907843991113941783170,
+        375793984420096156991,
+    )),
+    fi256(i256::from_parts(
+        258480267697776874311689391629784515944,
+        187896992210048078495,
+    )),
+    fi256(i256::from_parts(
+        299381317309357765090792011075392553529,
+        93948496105024039247,
+    )),
+    fi256(i256::from_parts(
+        319831842115148126302490810696657406221,
+        46974248052512019623,
+    )),
+    fi256(i256::from_parts(
+        330057104518043296386108646744597436805,
+        23487124026256009811,
+    )),
+    fi256(i256::from_parts(
+        335169735719490880112638619298230902626,
+        11743562013128004905,
+    )),
+    fi256(i256::from_parts(
+        337726051320214671811493737391255566853,
+        5871781006564002452,
+    )),
+    fi256(i256::from_parts(
+        168863025660107335908682759198909784653,
+        2935890503282001226,
+    )),
+    fi256(i256::from_parts(
+        84431512830053667954708365912365142480,
+        1467945251641000613,
+    )),
+    fi256(i256::from_parts(
+        212356939875496065709087359961180458237,
+        733972625820500306,
+    )),
+    fi256(i256::from_parts(
+        106178469937748032854549414141729451777,
+        366986312910250153,
+    )),
+    fi256(i256::from_parts(
+        223230418429343248158962727556891234449,
+        183493156455125076,
+    )),
+    fi256(i256::from_parts(
+        111615209214671624079481453374713417579,
+        91746578227562538,
+    )),
+    fi256(i256::from_parts(
+        55807604607335812039740737886890183834,
+        45873289113781269,
+    )),
+    fi256(i256::from_parts(
+        198044985764137137751557674059270882025,
+        22936644556890634,
+    )),
+    fi256(i256::from_parts(
+        99022492882068568875778837204628151560,
+        11468322278445317,
+    )),
+    fi256(i256::from_parts(
+        219652429901503516169576722340072270327,
+        5734161139222658,
+    )),
+    fi256(i256::from_parts(
+        109826214950751758084788361172770396266,
+        2867080569611329,
+    )),
+    fi256(i256::from_parts(
+        225054290935845110774081484302611086499,
+        1433540284805664,
+    )),
+    fi256(i256::from_parts(
+        112527145467922555387040742151348266079,
+        716770142402832,
+    )),
+    fi256(i256::from_parts(
+        56263572733961277693520371075679473393,
+        358385071201416,
+    )),
+    fi256(i256::from_parts(
+        28131786366980638846760185537840404241,
+        179192535600708,
+    )),
+    fi256(i256::from_parts(
+        14065893183490319423380092768920285563,
+        89596267800354,
+    )),
+    fi256(i256::from_parts(
+        7032946591745159711690046384460153212,
+        44798133900177,
+    )),
+    fi256(i256::from_parts(
+        173657656756341811587532326908114183638,
+        22399066950088,
+    )),
+    fi256(i256::from_parts(
+        86828828378170905793766163454057091982,
+        11199533475044,
+    )),
+    fi256(i256::from_parts(
+        43414414189085452896883081727028546011,
+        5599766737522,
+    )),
+    fi256(i256::from_parts(
+        21707207094542726448441540863514273008,
+        2799883368761,
+    )),
+    fi256(i256::from_parts(
+        180994787007740594955908074147641242232,
+        1399941684380,
+    )),
+    fi256(i256::from_parts(
+        90497393503870297477954037073820621116,
+        699970842190,
+    )),
+    fi256(i256::from_parts(
+        45248696751935148738977018536910310558,
+        349985421095,
+    )),
+    fi256(i256::from_parts(
+        192765531836436806101175812984339261007,
+        174992710547,
+    )),
+    fi256(i256::from_parts(
+        266523949378687634782275210208053736232,
+        87496355273,
+    )),
+    fi256(i256::from_parts(
+        303403158149813049122824908819910973844,
+        43748177636,
+    )),
+    fi256(i256::from_parts(
+        151701579074906524561412454409955486922,
+        21874088818,
+    )),
+    fi256(i256::from_parts(
+        75850789537453262280706227204977743461,
+        10937044409,
+    )),
+    fi256(i256::from_parts(
+        208066578229195862872040417318372977458,
+        5468522204,
+    )),
+    fi256(i256::from_parts(
+        104033289114597931436020208659186488729,
+        2734261102,
+    )),
+    fi256(i256::from_parts(
+        52016644557298965718010104329593244365,
+        1367130551,
+    )),
+    fi256(i256::from_parts(
+        196149505739118714590692355880680727910,
+        683565275,
+    )),
+    fi256(i256::from_parts(
+        268215936330028589027033481656224469683,
+        341782637,
+    )),
+    fi256(i256::from_parts(
+        304249151625483526245204044543996340570,
+        170891318,
+    )),
+    fi256(i256::from_parts(
+        152124575812741763122602022271998170285,
+        85445659,
+    )),
+    fi256(i256::from_parts(
+        246203471366840113292988314851883190870,
+        42722829,
+    )),
+    fi256(i256::from_parts(
+        293242919143889288378181461141825701163,
+        21361414,
+    )),
+    fi256(i256::from_parts(
+        146621459571944644189090730570912850582,
+        10680707,
+    )),
+    fi256(i256::from_parts(
+        243451913246441553826232669001340531019,
+        5340353,
+    )),
+    fi256(i256::from_parts(
+        291867140083690008644803638216554371237,
+        2670176,
+    )),
+    fi256(i256::from_parts(
+        145933570041845004322401819108277185619,
+        1335088,
+    )),
+    fi256(i256::from_parts(
+        72966785020922502161200909554138592809,
+        667544,
+    )),
+    fi256(i256::from_parts(
+        36483392510461251080600454777069296405,
+        333772,
+    )),
+    fi256(i256::from_parts(
+        18241696255230625540300227388534648202,
+        166886,
+    )),
+    fi256(i256::from_parts(
+        9120848127615312770150113694267324101,
+        83443,
+    )),
+    fi256(i256::from_parts(
+        174701607524276888116762360563017767779,
+        41721,
+    )),
+    fi256(i256::from_parts(
+        257491987222607675790068483997392989617,
+        20860,
+    )),
+    fi256(i256::from_parts(
+        128745993611303837895034241998696494809,
+        10430,
+    )),
+    fi256(i256::from_parts(
+        64372996805651918947517120999348247404,
+        5215,
+    )),
+    fi256(i256::from_parts(
+        202327681863295191205445864215558229430,
+        2607,
+    )),
+    fi256(i256::from_parts(
+        271305024392116827334410235823663220443,
+        1303,
+    )),
+    fi256(i256::from_parts(
+        305793695656527645398892421627715715950,
+        651,
+    )),
+    fi256(i256::from_parts(
+        323038031288733054431133514529741963703,
+        325,
+    )),
+    fi256(i256::from_parts(
+        331660199104835758947254060980755087579,
+        162,
+    )),
+    fi256(i256::from_parts(
+        165830099552417879473627030490377543790,
+        81,
+    )),
+    fi256(i256::from_parts(
+        253056233236678171468500818961072877623,
+        40,
+    )),
+    fi256(i256::from_parts(
+        126528116618339085734250409480536438811,
+        20,
+    )),
+    fi256(i256::from_parts(63264058309169542867125204740268219406, 10)),
+    fi256(i256::from_parts(31632029154584771433562602370134109703, 5)),
+    fi256(i256::from_parts(185957198037761617448468604900951160579, 2)),
+    fi256(i256::from_parts(92978599018880808724234302450475580290, 1)),
+    fi256(i256::from_parts(216630482969909636093804454941121895873, 0)),
+    fi256(i256::from_parts(108315241484954818046902227470560947936, 0)),
+    fi256(i256::from_parts(54157620742477409023451113735280473968, 0)),
+    fi256(i256::from_parts(27078810371238704511725556867640236984, 0)),
+];
+pub const CORDIC_GAIN128: fi256 = fi256(i256::from_parts(
+    299431374094560065106747945935708184495,
+    103318733022254464881547511815561191540,
+));
+
+pub const LOG2_TABLE8: [fi16; 10] = [
+    fi16(19168),
+    fi16(10549),
+    fi16(5568),
+    fi16(2866),
+    fi16(1455),
+    fi16(733),
+    fi16(368),
+    fi16(184),
+    fi16(92),
+    fi16(46),
+];
+
+pub const LOG2_TABLE16: [fi32; 18] = [
+    fi32(1256197405),
+    fi32(691335320),
+    fi32(364911162),
+    fi32(187825021),
+    fi32(95335645),
+    fi32(48034513),
+    fi32(24110347),
+    fi32(12078627),
+    fi32(6045200),
+    fi32(3024074),
+    fi32(1512406),
+    fi32(756295),
+    fi32(378171),
+    fi32(189091),
+    fi32(94547),
+    fi32(47274),
+    fi32(23637),
+    fi32(11819),
+];
+
+pub const LOG2_TABLE32: [fi64; 34] = [
+    fi64(5395326771760153552),
+    fi64(2969262588262028797),
+    fi64(1567281506665531296),
+    fi64(806702324252248894),
+    fi64(409463479091552716),
+    fi64(206306661212243249),
+    fi64(103553153721468184),
+    fi64(51877309754729402),
+    fi64(25963936233411987),
+    fi64(12988300785084584),
+    fi64(6495735104755651),
+    fi64(3248263923818468),
+    fi64(1624231078958297),
+    fi64(812140321765996),
+    fi64(406076356832843),
+    fi64(203039727451153),
+    fi64(101520250990169),
+    fi64(50760222311971),
+    fi64(25380135360299),
+    fi64(12690073731240),
+    fi64(6345038378394),
+    fi64(3172519567391),
+    fi64(1586259878244),
+    fi64(793129962759),
+    fi64(396564987289),
+    fi64(198282495122),
+    fi64(99141247930),
+    fi64(49570624057),
+    fi64(24785312052),
+    fi64(12392656032),
+    fi64(6196328017),
+    fi64(3098164009),
+    fi64(1549082005),
+    fi64(774541002),
+];
+
+pub const LOG2_TABLE64: [fi128; 66] = [
+    fi128(99526212152693099139219170928915502825),
+    fi128(54773227053310064141943150001935857306),
+    fi128(28911240844916966546751038141946899921),
+    fi128(14881031319147893387481071936909061474),
+    fi128(7553268006332594967107397592521130568),
+    fi128(3805686180083752378983606090874314537),
+    fi128(1910218524725427423112606903585535044),
+    fi128(956967456278049315287446957693476207),
+    fi128(478950086843865270466845740202244780),
+    fi128(239591860534816171080078976981857722),
+    fi128(119825163048038399327795814839320907),
+    fi128(59919893286542864141752991129851979),
+    fi128(29961775030108833486883267771780173),
+    fi128(14981344667557448621397002469294710),
+    fi128(7490786628879809808558101222239829),
+    fi128(3745421889087162066194139156487366),
+    fi128(1872718088314397912807014260404201),
+    fi128(936360830113528772859874121397594),
+    fi128(468180861547550057165215776287480),
+    fi128(234090542396684349410030440810380),
+    fi128(117045299104096117871443755432471),
+    fi128(58522656528489871351439544096131),
+    fi128(29261330008355804607923250625426),
+    fi128(14630665440205671515563018054267),
+    fi128(7315332829109784558001689184375),
+    fi128(3657666441806630291220932164535),
+    fi128(1828833227716249750186122263019),
+    fi128(914416615561358538927054710845),
+    fi128(457208308206487687008285707537),
+    fi128(228604154209695948088614937427),
+    fi128(114302077131461000215210801642),
+    fi128(57151038572383756653429398059),
+    fi128(28575519287855192463557968840),
+    fi128(14287759644343424766038210685),
+    fi128(7143879822275669516589963010),
+    fi128(3571939911163824041688452310),
+    fi128(1785969955588409341692688405),
+    fi128(892984977795829001058471583),
+    fi128(446492488898320583082269114),
+    fi128(223246244449261812179393072),
+    fi128(111623122224656286249261188),
+    fi128(55811561112334488164521760),
+    fi128(27905780556168830342233672),
+    fi128(13952890278084811736110034),
+    fi128(6976445139042505009303316),
+    fi128(3488222569521277289963733),
+    fi128(1744111284760644841309885),
+    fi128(872055642380323969736947),
+    fi128(436027821190162372138975),
+    fi128(218013910595081282887113),
+    fi128(109006955297540665647963),
+    fi128(54503477648770338875083),
+    fi128(27251738824385170950317),
+    fi128(13625869412192585853352),
+    fi128(6812934706096293021225),
+    fi128(3406467353048146534249),
+    fi128(1703233676524073273034),
+    fi128(851616838262036637994),
+    fi128(425808419131018319366),
+    fi128(212904209565509159776),
+    fi128(106452104782754579911),
+    fi128(53226052391377289961),
+    fi128(26613026195688644982),
+    fi128(13306513097844322491),
+    fi128(6653256548922161246),
+    fi128(3326628274461080623),
+];
+
+pub const LOG2_TABLE128: [fi256; 130] = [
+    fi256(i256::from_parts(
+        194700490412231863012976508613804499196,
+        99526212152693099139219170928915502824,
+    )),
+    fi256(i256::from_parts(
+        237727607663175509536160494188688771898,
+        54773227053310064141943150001935857305,
+    )),
+    fi256(i256::from_parts(
+        49118613903525262562578409795840786935,
+        28911240844916966546751038141946899921,
+    )),
+    fi256(i256::from_parts(
+        304602170349519530541829515523108947643,
+        14881031319147893387481071936909061473,
+    )),
+    fi256(i256::from_parts(
+        66064050703157515695668202823272353671,
+        7553268006332594967107397592521130568,
+    )),
+    fi256(i256::from_parts(
+        256971596659978511854784344505940207803,
+        3805686180083752378983606090874314536,
+    )),
+    fi256(i256::from_parts(
+        38287799990185732355493666848729432455,
+        1910218524725427423112606903585535044,
+    )),
+    fi256(i256::from_parts(
+        5732011260836946935878976415935387820,
+        956967456278049315287446957693476207,
+    )),
+    fi256(i256::from_parts(
+        5832426207424293240413881108866306727,
+        478950086843865270466845740202244780,
+    )),
+    fi256(i256::from_parts(
+        241202197236236638835475822747890828965,
+        239591860534816171080078976981857721,
+    )),
+    fi256(i256::from_parts(
+        156194189934933873353322537037629425809,
+        119825163048038399327795814839320907,
+    )),
+    fi256(i256::from_parts(
+        65884321598546033279496660882483481566,
+        59919893286542864141752991129851979,
+    )),
+    fi256(i256::from_parts(
+        183462375978542056404328056603913838794,
+        29961775030108833486883267771780172,
+    )),
+    fi256(i256::from_parts(
+        281184712570505726980893571006558386487,
+        14981344667557448621397002469294709,
+    )),
+    fi256(i256::from_parts(
+        42940321853770367110201810293705082321,
+        7490786628879809808558101222239829,
+    )),
+    fi256(i256::from_parts(
+        306560520085294921041154905253250943270,
+        3745421889087162066194139156487365,
+    )),
+    fi256(i256::from_parts(
+        13645994444815076414859977137085416664,
+        1872718088314397912807014260404201,
+    )),
+    fi256(i256::from_parts(
+        87501290088836001189266225944057005996,
+        936360830113528772859874121397594,
+    )),
+    fi256(i256::from_parts(
+        2912929878600744637921502949789089115,
+        468180861547550057165215776287480,
+    )),
+    fi256(i256::from_parts(
+        253358094166716150664871189611238390142,
+        234090542396684349410030440810379,
+    )),
+    fi256(i256::from_parts(
+        6630585706526192271340008461993432572,
+        117045299104096117871443755432471,
+    )),
+    fi256(i256::from_parts(
+        54448426314946634239478801270077013792,
+        58522656528489871351439544096131,
+    )),
+    fi256(i256::from_parts(
+        321035093223952037534999149793278397671,
+        29261330008355804607923250625425,
+    )),
+    fi256(i256::from_parts(
+        211224541502704679257338698066745674716,
+        14630665440205671515563018054266,
+    )),
+    fi256(i256::from_parts(
+        146150428740233305002043395628498201465,
+        7315332829109784558001689184375,
+    )),
+    fi256(i256::from_parts(
+        66309791862847562149123264645245740541,
+        3657666441806630291220932164535,
+    )),
+    fi256(i256::from_parts(
+        48865950409701425250759004707941967114,
+        1828833227716249750186122263019,
+    )),
+    fi256(i256::from_parts(
+        83383343225239522267909708204692034337,
+        914416615561358538927054710845,
+    )),
+    fi256(i256::from_parts(
+        296352163569717628492618824286593504282,
+        457208308206487687008285707536,
+    )),
+    fi256(i256::from_parts(
+        180302268587277676915847003029750765351,
+        228604154209695948088614937426,
+    )),
+    fi256(i256::from_parts(
+        24930369227418405740215790513279659259,
+        114302077131461000215210801642,
+    )),
+    fi256(i256::from_parts(
+        276098666499869776542552589517657175734,
+        57151038572383756653429398058,
+    )),
+    fi256(i256::from_parts(
+        266323084706298061482797229628912460000,
+        28575519287855192463557968839,
+    )),
+    fi256(i256::from_parts(
+        39819826699235718111129292768118086225,
+        14287759644343424766038210685,
+    )),
+    fi256(i256::from_parts(
+        151354016545750895031690118610294737362,
+        7143879822275669516589963010,
+    )),
+    fi256(i256::from_parts(
+        303363234644153246321709215966893290231,
+        3571939911163824041688452309,
+    )),
+    fi256(i256::from_parts(
+        195739169080382492205703414903372851332,
+        1785969955588409341692688404,
+    )),
+    fi256(i256::from_parts(
+        86008400753890708688030284207289764797,
+        892984977795829001058471583,
+    )),
+    fi256(i256::from_parts(
+        63764022681021152398202042643255043176,
+        446492488898320583082269114,
+    )),
+    fi256(i256::from_parts(
+        135742022694417787649378056439830456370,
+        223246244449261812179393072,
+    )),
+    fi256(i256::from_parts(
+        37049915395858788571995795766118968113,
+        111623122224656286249261188,
+    )),
+    fi256(i256::from_parts(
+        312102316384636029755455975947669515144,
+        55811561112334488164521759,
+    )),
+    fi256(i256::from_parts(
+        267105826948385946641881278171447158515,
+        27905780556168830342233671,
+    )),
+    fi256(i256::from_parts(
+        304263833360394694448195985825489480108,
+        13952890278084811736110033,
+    )),
+    fi256(i256::from_parts(
+        154192021424359857514679102915865100174,
+        6976445139042505009303316,
+    )),
+    fi256(i256::from_parts(
+        35315449862819439773727743935355026310,
+        3488222569521277289963733,
+    )),
+    fi256(i256::from_parts(
+        92313140053018738546634764777004646109,
+        1744111284760644841309885,
+    )),
+    fi256(i256::from_parts(
+        107359465122493247571019899387488530160,
+        872055642380323969736947,
+    )),
+    fi256(i256::from_parts(
+        281657403842137172491390654182036448942,
+        436027821190162372138974,
+    )),
+    fi256(i256::from_parts(
+        240358474129072036288715959816912991971,
+        218013910595081282887112,
+    )),
+    fi256(i256::from_parts(
+        230132279162104434637677424249456039116,
+        109006955297540665647962,
+    )),
+    fi256(i256::from_parts(
+        312695584480330170438447022180261702215,
+        54503477648770338875082,
+    )),
+    fi256(i256::from_parts(
+        290825745309521266523043200241675143300,
+        27251738824385170950316,
+    )),
+    fi256(i256::from_parts(
+        93961769206152822366392119687178872747,
+        13625869412192585853352,
+    )),
+    fi256(i256::from_parts(
+        204259292203179660146695899853354304276,
+        6812934706096293021224,
+    )),
+    fi256(i256::from_parts(
+        141449248001838888558672273245670659775,
+        3406467353048146534249,
+    )),
+    fi256(i256::from_parts(
+        335766299666713462278754343441995486787,
+        1703233676524073273033,
+    )),
+    fi256(i256::from_parts(
+        106537681154456800063035766940886442564,
+        851616838262036637994,
+    )),
+    fi256(i256::from_parts(
+        165538361002855777089019200225083913811,
+        425808419131018319366,
+    )),
+    fi256(i256::from_parts(
+        195907152338069403178206229929488860897,
+        212904209565509159775,
+    )),
+    fi256(i256::from_parts(
+        296379252588664318792149282231158362435,
+        106452104782754579910,
+    )),
+    fi256(i256::from_parts(
+        70190157803887640749687543407306223421,
+        53226052391377289961,
+    )),
+    fi256(i256::from_parts(
+        15595211779332690819699102059339452134,
+        26613026195688644982,
+    )),
+    fi256(i256::from_parts(
+        130528526704365486833135374503348712957,
+        13306513097844322491,
+    )),
+    fi256(i256::from_parts(
+        266088177016326760505739760973208749240,
+        6653256548922161245,
+    )),
+    fi256(i256::from_parts(
+        310855954519551308324136290971054869646,
+        3326628274461080622,
+    )),
+    fi256(i256::from_parts(
+        157345647897505328246988911461063259339,
+        1663314137230540311,
+    )),
+    fi256(i256::from_parts(
+        249293425068654314376415199600723969926,
+        831657068615270155,
+    )),
+    fi256(i256::from_parts(
+        294907750409654493550203669637376184123,
+        415828534307635077,
+    )),
+    fi256(i256::from_parts(
+        317625022269011004664366380825173850497,
+        207914267153817538,
+    )),
+    fi256(i256::from_parts(
+        158820002035434133871577507330277229594,
+        103957133576908769,
+    )),
+    fi256(i256::from_parts(
+        249553057203418456552324637403575283008,
+        51978566788454384,
+    )),
+    fi256(i256::from_parts(
+        124776996783017267747374463806567030424,
+        25989283394227192,
+    )),
+    fi256(i256::from_parts(
+        62388615436835643741490268191871018479,
+        12994641697113596,
+    )),
+    fi256(i256::from_parts(
+        31194336979749574337695893169631467061,
+        6497320848556798,
+    )),
+    fi256(i256::from_parts(
+        15597175805207725285585636353433358237,
+        3248660424278399,
+    )),
+    fi256(i256::from_parts(
+        177939773191906328903664544334779395429,
+        1624330212139199,
+    )),
+    fi256(i256::from_parts(
+        259111070513630704815815681493821481639,
+        812165106069599,
+    )),
+    fi256(i256::from_parts(
+        299696718831586661297669170865432144291,
+        406082553034799,
+    )),
+    fi256(i256::from_parts(
+        319989542904838081670040395749259549583,
+        203041276517399,
+    )),
+    fi256(i256::from_parts(
+        330135954920032152389087128240678729356,
+        101520638258699,
+    )),
+    fi256(i256::from_parts(
+        335209160922271277881825774498764683354,
+        50760319129349,
+    )),
+    fi256(i256::from_parts(
+        337745763922051363161498917630901750734,
+        25380159564674,
+    )),
+    fi256(i256::from_parts(
+        168872881961137304702974140481859701211,
+        12690079782337,
+    )),
+    fi256(i256::from_parts(
+        254577624441065789863730544373416162796,
+        6345039891168,
+    )),
+    fi256(i256::from_parts(
+        127288812220539871377004314790858633014,
+        3172519945584,
+    )),
+    fi256(i256::from_parts(
+        63644406110271679799786918046466954411,
+        1586259972792,
+    )),
+    fi256(i256::from_parts(
+        31822203055136275927714649185992886681,
+        793129986396,
+    )),
+    fi256(i256::from_parts(
+        15911101527568246970812622133686295710,
+        396564993198,
+    )),
+    fi256(i256::from_parts(
+        7955550763784150737145135452015610947,
+        198282496599,
+    )),
+    fi256(i256::from_parts(
+        174118958842361313913194577538185026975,
+        99141248299,
+    )),
+    fi256(i256::from_parts(
+        257200662881649890391518269009049898159,
+        49570624149,
+    )),
+    fi256(i256::from_parts(
+        298741514901294177353254857351427374543,
+        24785312074,
+    )),
+    fi256(i256::from_parts(
+        149370757450647088783079533458468267206,
+        12392656037,
+    )),
+    fi256(i256::from_parts(
+        244826562185792776149840096640806884314,
+        6196328018,
+    )),
+    fi256(i256::from_parts(
+        122413281092896388081573304869325603403,
+        3098164009,
+    )),
+    fi256(i256::from_parts(
+        231347824006917425774137270287777447741,
+        1549082004,
+    )),
+    fi256(i256::from_parts(
+        115673912003458712887484463678196358948,
+        774541002,
+    )),
+    fi256(i256::from_parts(
+        57836956001729356443846188972675088244,
+        387270501,
+    )),
+    fi256(i256::from_parts(
+        199059661461333909953636387485615877042,
+        193635250,
+    )),
+    fi256(i256::from_parts(
+        99529830730666954976824691063656495319,
+        96817625,
+    )),
+    fi256(i256::from_parts(
+        219906098825802709220101273577924492587,
+        48408812,
+    )),
+    fi256(i256::from_parts(
+        109953049412901354610051042871515281093,
+        24204406,
+    )),
+    fi256(i256::from_parts(
+        54976524706450677305025622956395899247,
+        12102203,
+    )),
+    fi256(i256::from_parts(
+        197629445813694570384200140574241620026,
+        6051101,
+    )),
+    fi256(i256::from_parts(
+        268955906367316516923787380348044806910,
+        3025550,
+    )),
+    fi256(i256::from_parts(
+        134477953183658258461893691760282376247,
+        1512775,
+    )),
+    fi256(i256::from_parts(
+        237380160052298360962634149992590287050,
+        756387,
+    )),
+    fi256(i256::from_parts(
+        288831263486618412213004378811320497552,
+        378193,
+    )),
+    fi256(i256::from_parts(
+        314556815203778437838189493146329666579,
+        189096,
+    )),
+    fi256(i256::from_parts(
+        157278407601889218919094746579361161308,
+        94548,
+    )),
+    fi256(i256::from_parts(
+        78639203800944609459547373291229662659,
+        47274,
+    )),
+    fi256(i256::from_parts(
+        39319601900472304729773686646002101831,
+        23637,
+    )),
+    fi256(i256::from_parts(
+        189800984410705384096574147038981974269,
+        11818,
+    )),
+    fi256(i256::from_parts(
+        94900492205352692048287073519515191541,
+        5909,
+    )),
+    fi256(i256::from_parts(
+        217591429563145577755830840475647752600,
+        2954,
+    )),
+    fi256(i256::from_parts(
+        108795714781572788877915420237825389075,
+        1477,
+    )),
+    fi256(i256::from_parts(
+        224539040851255626170645013834797178460,
+        738,
+    )),
+    fi256(i256::from_parts(
+        112269520425627813085322506917398683778,
+        369,
+    )),
+    fi256(i256::from_parts(
+        226275943673283138274348557174583471254,
+        184,
+    )),
+    fi256(i256::from_parts(
+        113137971836641569137174278587291741536,
+        92,
+    )),
+    fi256(i256::from_parts(56568985918320784568587139293645872246, 46)),
+    fi256(i256::from_parts(28284492959160392284293569646822936492, 23)),
+    fi256(i256::from_parts(
+        184283429940049427873834088539295574066,
+        11,
+    )),
+    fi256(i256::from_parts(262282898430493945668604347985531892784, 5)),
+    fi256(i256::from_parts(301282632675716204565989477708650052126, 2)),
+    fi256(i256::from_parts(150641316337858102282994738854325026064, 1)),
+    fi256(i256::from_parts(245461841629398282873184673143046618761, 0)),
+    fi256(i256::from_parts(122730920814699141436592336571523309380, 0)),
+    fi256(i256::from_parts(61365460407349570718296168285761654690, 0)),
+];
+
+pub const SQRT_LUT8: [fi8; 128] = [
+    fi8(0),
+    fi8(11),
+    fi8(16),
+    fi8(19),
+    fi8(22),
+    fi8(25),
+    fi8(27),
+    fi8(29),
+    fi8(32),
+    fi8(33),
+    fi8(35),
+    fi8(37),
+    fi8(39),
+    fi8(40),
+    fi8(42),
+    fi8(43),
+    fi8(45),
+    fi8(46),
+    fi8(48),
+    fi8(49),
+    fi8(50),
+    fi8(51),
+    fi8(53),
+    fi8(54),
+    fi8(55),
+    fi8(56),
+    fi8(57),
+    fi8(58),
+    fi8(59),
+    fi8(60),
+    fi8(61),
+    fi8(62),
+    fi8(64),
+    fi8(64),
+    fi8(65),
+    fi8(66),
+    fi8(67),
+    fi8(68),
+    fi8(69),
+    fi8(70),
+    fi8(71),
+    fi8(72),
+    fi8(73),
+    fi8(74),
+    fi8(75),
+    fi8(75),
+    fi8(76),
+    fi8(77),
+    fi8(78),
+    fi8(79),
+    fi8(80),
+    fi8(80),
+    fi8(81),
+    fi8(82),
+    fi8(83),
+    fi8(83),
+    fi8(84),
+    fi8(85),
+    fi8(86),
+    fi8(86),
+    fi8(87),
+    fi8(88),
+    fi8(89),
+    fi8(89),
+    fi8(90),
+    fi8(91),
+    fi8(91),
+    fi8(92),
+    fi8(93),
+    fi8(93),
+    fi8(94),
+    fi8(95),
+    fi8(96),
+    fi8(96),
+    fi8(97),
+    fi8(97),
+    fi8(98),
+    fi8(99),
+    fi8(99),
+    fi8(100),
+    fi8(101),
+    fi8(101),
+    fi8(102),
+    fi8(103),
+    fi8(103),
+    fi8(104),
+    fi8(104),
+    fi8(105),
+    fi8(106),
+    fi8(106),
+    fi8(107),
+    fi8(107),
+    fi8(108),
+    fi8(109),
+    fi8(109),
+    fi8(110),
+    fi8(110),
+    fi8(111),
+    fi8(112),
+    fi8(112),
+    fi8(113),
+    fi8(113),
+    fi8(114),
+    fi8(114),
+    fi8(115),
+    fi8(115),
+    fi8(116),
+    fi8(117),
+    fi8(117),
+    fi8(118),
+    fi8(118),
+    fi8(119),
+    fi8(119),
+    fi8(120),
+    fi8(120),
+    fi8(121),
+    fi8(121),
+    fi8(122),
+    fi8(122),
+    fi8(123),
+    fi8(123),
+    fi8(124),
+    fi8(124),
+    fi8(125),
+    fi8(125),
+    fi8(126),
+    fi8(126),
+    fi8(127),
+];
+
 pub const SIMPLE_ISQRT_LUT: [fi16; 24] = [
     fi16(32684),
     fi16(28936),
@@ -0,0 +1,245 @@
+//! CORDIC (COordinate Rotation DIgital Computer) functions that only use
+//! additions, shifts, and table lookups in their main loops. These are useful
+//! for targets without fast multipliers. Angles are in half turns, the same as
+//! for `cos_sin_pi_taylor`.
+
+use crate::{Fracint, FracintDouble, constants::*, impl_signed::*, internal::*};
+
+/// Rotation mode CORDIC, returns `(cos(pi * z), sin(pi * z))` for
+/// `-0.5 <= z <= 0.5`. `gain` is the reciprocal of the gain caused by
+/// `atan_table.len()` iterations.
+fn cordic_rotate<F: Fracint>(mut z: F, atan_table: &[F], gain: F) -> (F, F) {
+    // starting with `gain` instead of one cancels out the gain of the rotations
+    let mut x = gain;
+    let mut y = F::ZERO;
+    for (i, atan) in atan_table.iter().enumerate() {
+        let x_shr = x >> i;
+        let y_shr = y >> i;
+        if z.is_negative() {
+            x = x.saturating_add(y_shr);
+            y = y.saturating_sub(x_shr);
+            z = z.wrapping_add(*atan);
+        } else {
+            x = x.saturating_sub(y_shr);
+            y = y.saturating_add(x_shr);
+            z = z.wrapping_sub(*atan);
+        }
+    }
+    (x, y)
+}
+
+/// Vectoring mode CORDIC, rotates `(x, y)` onto the positive x axis and
+/// returns the x value (multiplied by the CORDIC gain) and the angle it was
+/// rotated by in half turns. `x` should be nonnegative and the magnitude
+/// small enough that the gain does not cause overflow.
+fn cordic_vector<F: Fracint>(mut x: F, mut y: F, atan_table: &[F]) -> (F, F) {
+    let mut z = F::ZERO;
+    for (i, atan) in atan_table.iter().enumerate() {
+        let x_shr = x >> i;
+        let y_shr = y >> i;
+        if y.is_negative() {
+            x = x.wrapping_sub(y_shr);
+            y = y.wrapping_add(x_shr);
+            z = z.wrapping_sub(*atan);
+        } else {
+            x = x.wrapping_add(y_shr);
+            y = y.wrapping_sub(x_shr);
+            z = z.wrapping_add(*atan);
+        }
+    }
+    (x, z)
+}
+
+/// Returns `(cos(pi * angle), sin(pi * angle))`
+fn cordic_cos_sin<F: Fracint>(angle: F, atan_table: &[F], gain: F) -> (F, F) {
+    // reduce to the right half plane where the iterations converge
+    if angle > (F::ONE >> 1) || angle < (F::NEG_ONE >> 1) {
+        let (x, y) = cordic_rotate(angle.wrapping_add(F::MIN), atan_table, gain);
+        (x.wrapping_neg(), y.wrapping_neg())
+    } else {
+        cordic_rotate(angle, atan_table, gain)
+    }
+}
+
+/// Returns `(hypot(x, y) * cordic_gain / 4 << shift, shift, atan2(y, x) / pi)`
+fn cordic_polar<F: Fracint>(x: F, y: F, atan_table: &[F]) -> (F, usize, F) {
+    if x.is_zero() && y.is_zero() {
+        return (F::ZERO, 0, F::ZERO)
+    }
+    // the magnitude can be up to `sqrt(2)` and the CORDIC gain is about 1.65, so
+    // we divide by 4 to prevent overflow
+    let mut x = x >> 2;
+    let mut y = y >> 2;
+    // scale up small vectors so that the shifts in the iterations do not lose
+    // precision, the angle does not depend on the scale
+    let mut shift = 0;
+    let eighth = F::ULP << (F::BITS - 4);
+    while (x.wrapping_abs() < eighth) && (y.wrapping_abs() < eighth) {
+        x <<= 1;
+        y <<= 1;
+        shift += 1;
+    }
+    // reduce to the right half plane where the iterations converge
+    if x.is_negative() {
+        let (mag, angle) = cordic_vector(x.wrapping_neg(), y.wrapping_neg(), atan_table);
+        (mag, shift, angle.wrapping_add(F::MIN))
+    } else {
+        let (mag, angle) = cordic_vector(x, y, atan_table);
+        (mag, shift, angle)
+    }
+}
+
+/// Returns `atan2(y, x) / pi`
+fn cordic_atan2<F: Fracint>(y: F, x: F, atan_table: &[F]) -> F {
+    if y.is_zero() {
+        // exactly on the x axis, use the positive side for half of a turn like
+        // other `atan2`s do
+        return if x.is_negative() { F::ONE } else { F::ZERO }
+    }
+    let (_, _, angle) = cordic_polar(x, y, atan_table);
+    // the iterations can overshoot across the x axis, clamp to the half plane
+    // that `y` is in
+    if y.is_negative() == angle.is_negative() {
+        angle
+    } else if !x.is_negative() {
+        F::ZERO
+    } else if y.is_negative() {
+        F::NEG_ONE
+    } else {
+        F::ONE
+    }
+}
+
+/// Returns `hypot(x, y)` saturated to `ONE`
+fn cordic_hypot<F: Fracint>(x: F, y: F, atan_table: &[F], gain: F) -> F {
+    let (mag, shift, _) = cordic_polar(x, y, atan_table);
+    let mag = mag.wrapping_mul(gain) >> shift;
+    // undo the division by 4
+    if mag >= (F::ULP << (F::BITS - 3)) {
+        F::ONE
+    } else {
+        mag << 2
+    }
+}
+
+/// Returns `sqrt(1 - x^2)`, `x` should not be `MIN`. This is called with the
+/// double width type, where the square of a widened `x` is exact so that there
+/// is no catastrophic cancellation when `x` is near one.
+fn cathetus<F: Fracint>(x: F) -> F {
+    F::ONE.wrapping_sub(x.wrapping_mul(x)).sqrt_fast()
+}
+
+/// Returns `atan(x) / pi`
+fn cordic_atan<F: Fracint>(x: F, atan_table: &[F]) -> F {
+    cordic_atan2(x, F::ONE, atan_table)
+}
+
+/// Returns `asin(x) / pi`, `x` should not be `MIN`
+fn cordic_asin<F: Fracint>(x: F, atan_table: &[F]) -> F {
+    cordic_atan2(x, cathetus(x), atan_table)
+}
+
+/// Returns `acos(x) / pi`, `x` should not be `MIN`
+fn cordic_acos<F: Fracint>(x: F, atan_table: &[F]) -> F {
+    cordic_atan2(cathetus(x), x, atan_table)
+}
+
+macro_rules! impl_cordic {
+    ($($ty:ident, $atan_table:expr, $gain:expr, $to_ext:expr, $from_ext:expr);*;) => {$(
+        impl $ty {
+            /// Calculates `(cos(pi * self), sin(pi * self))` using CORDIC, where
+            /// `self` is an angle in half turns.
+            ///
+            /// The main loop only uses additions and shifts. The result is within
+            /// 1 ULP of the true value.
+            pub fn cordic_cos_sin(self) -> ($ty, $ty) {
+                let (x, y) = cordic_cos_sin($to_ext(self), &$atan_table, $gain);
+                ($from_ext(x), $from_ext(y))
+            }
+
+            /// Calculates the four quadrant arctangent of `self / x` using CORDIC
+            /// (with `self` being the `y` coordinate). The result is an angle in
+            /// half turns in the range `[NEG_ONE, ONE]`, and `fiN::ZERO` is
+            /// returned if both inputs are zero.
+            ///
+            /// The main loop only uses additions and shifts. The result is within
+            /// 1 ULP of the true value.
+            pub fn cordic_atan2(self, x: $ty) -> $ty {
+                $from_ext(cordic_atan2($to_ext(self), $to_ext(x), &$atan_table))
+            }
+
+            /// Calculates `sqrt(self^2 + rhs^2)` using CORDIC, saturating to
+            /// `fiN::ONE`.
+            ///
+            /// The main loop only uses additions and shifts, and one
+            /// multiplication is used to correct for the CORDIC gain. The result
+            /// is within 1 ULP of the true value.
+            pub fn cordic_hypot(self, rhs: $ty) -> $ty {
+                $from_ext(cordic_hypot($to_ext(self), $to_ext(rhs), &$atan_table, $gain))
+            }
+
+            /// Calculates the arctangent of `self` in half turns, the result is
+            /// in the range `[-0.25, 0.25]`.
+            ///
+            /// `fiN::ONE` and `fiN::NEG_ONE` are treated as being exactly one
+            /// and negative one, and `fiN::MIN` is treated the same as
+            /// `fiN::NEG_ONE`. The results for them are exactly 0.25 and -0.25.
+            /// Other inputs have results within 1 ULP of the true value.
+            pub fn atan(self) -> $ty {
+                let quarter = $ty::ULP << ($ty::BITS - 3);
+                if self == $ty::ONE {
+                    quarter
+                } else if self <= $ty::NEG_ONE {
+                    quarter.wrapping_neg()
+                } else {
+                    $from_ext(cordic_atan($to_ext(self), &$atan_table))
+                }
+            }
+
+            /// Calculates the arcsine of `self` in half turns, the result is in
+            /// the range `[-0.5, 0.5]`.
+            ///
+            /// `fiN::ONE` and `fiN::NEG_ONE` are treated as being exactly one
+            /// and negative one, and `fiN::MIN` is treated the same as
+            /// `fiN::NEG_ONE`. The results for them are exactly 0.5 and -0.5.
+            /// Other inputs have results within 1 ULP of the true value.
+            pub fn asin(self) -> $ty {
+                let half = $ty::ULP << ($ty::BITS - 2);
+                if self == $ty::ONE {
+                    half
+                } else if self <= $ty::NEG_ONE {
+                    half.wrapping_neg()
+                } else {
+                    $from_ext(cordic_asin($to_ext(self), &$atan_table))
+                }
+            }
+
+            /// Calculates the arccosine of `self` in half turns, the result is
+            /// in the range `[0, 1]` (with a half turn being represented by
+            /// `fiN::ONE`).
+            ///
+            /// `fiN::ONE` and `fiN::NEG_ONE` are treated as being exactly one
+            /// and negative one, and `fiN::MIN` is treated the same as
+            /// `fiN::NEG_ONE`. The results for them are exactly `fiN::ZERO`
+            /// and `fiN::ONE`. Other inputs have results within 1 ULP of the
+            /// true value.
+            pub fn acos(self) -> $ty {
+                if self == $ty::ONE {
+                    $ty::ZERO
+                } else if self <= $ty::NEG_ONE {
+                    $ty::ONE
+                } else {
+                    $from_ext(cordic_acos($to_ext(self), &$atan_table))
+                }
+            }
+        }
+    )*};
+}
+
+impl_cordic!(
+    fi8, CORDIC_ATAN8, CORDIC_GAIN8, |x: fi8| x.widen(), |x: fi16| round_half(x);
+    fi16, CORDIC_ATAN16, CORDIC_GAIN16, |x: fi16| x.widen(), |x: fi32| round_half(x);
+    fi32, CORDIC_ATAN32, CORDIC_GAIN32, |x: fi32| x.widen(), |x: fi64| round_half(x);
+    fi64, CORDIC_ATAN64, CORDIC_GAIN64, |x: fi64| x.widen(), |x: fi128| round_half(x);
+    fi128, CORDIC_ATAN128, CORDIC_GAIN128, |x: fi128| x.widen(), |x: fi256| round_half(x);
+);
@@ -0,0 +1,84 @@
+//! Exponential and logarithm functions. These use the shift-and-add method
+//! with a table of `log2(1 + 2^-i)`.
+
+use crate::{Fracint, FracintDouble, constants::*, impl_signed::*, internal::*};
+
+/// Returns `2^z / 2` for `0 < z < 1`
+fn exp2_sub1<F: Fracint>(mut z: F, table: &[F]) -> F {
+    let mut y = F::ULP << (F::BITS - 2);
+    for (i, t) in table.iter().enumerate() {
+        if z >= *t {
+            z = z.wrapping_sub(*t);
+            // multiply by `1 + 2^-(i + 1)`
+            y = y.wrapping_add(y >> (i + 1));
+        }
+    }
+    y
+}
+
+/// Returns `log2(y) + 1` for `0.5 <= y < 1`
+fn log2_add1<F: Fracint>(y: F, table: &[F]) -> F {
+    // find the product of `1 + 2^-i` factors closest to `y` from below
+    let mut p = F::ULP << (F::BITS - 2);
+    let mut acc = F::ZERO;
+    for (i, t) in table.iter().enumerate() {
+        // the shifted `p` can be truncated to zero for the last entries
+        if let Some(q) = p.checked_add(p >> (i + 1))
+            && (q != p)
+            && (q <= y)
+        {
+            p = q;
+            acc = acc.saturating_add(*t);
+        }
+    }
+    acc
+}
+
+macro_rules! impl_exp_log {
+    ($($ty:ident, $ext:ident, $table:expr, $to_ext:expr, $from_ext:expr);*;) => {$(
+        impl $ty {
+            /// Calculates `2^(-self)` for `self` in the range `[0, 1)`, the
+            /// result is in the range `(0.5, 1]` with `fiN::ONE` representing
+            /// one. Negative inputs return `fiN::ONE`.
+            ///
+            /// This uses only integer additions, shifts, and comparisons. The
+            /// result is within 1 ULP of the true value.
+            pub fn exp2_neg(self) -> $ty {
+                if self <= $ty::ZERO {
+                    return $ty::ONE
+                }
+                // `2^(-x) = 2^(1 - x) / 2`
+                let z = $ext::ONE.wrapping_sub($to_ext(self)).wrapping_add($ext::ULP);
+                $from_ext(exp2_sub1(z, &$table))
+            }
+
+            /// Calculates the base 2 logarithm of `self`, returning `None` if
+            /// `self` is not positive. The result is an `(exponent, mantissa)`
+            /// tuple where `log2(self) = exponent + mantissa`, the exponent
+            /// is the floor of the logarithm and is always negative, and the
+            /// mantissa is in the range `[0, 1)`.
+            ///
+            /// This uses only integer additions, shifts, and comparisons. The
+            /// sum is within 1 ULP of the true value.
+            pub fn log2(self) -> Option<(i32, $ty)> {
+                if self <= $ty::ZERO {
+                    return None
+                }
+                // normalize to `[0.5, 1)`
+                let shift = self.as_int().leading_zeros() - 1;
+                let y = self << (shift as usize);
+                let exponent = -(shift as i32) - 1;
+                // `log2(y) + 1` in the range `[0, 1)`
+                Some((exponent, $from_ext(log2_add1($to_ext(y), &$table))))
+            }
+        }
+    )*};
+}
+
+impl_exp_log!(
+    fi8, fi16, LOG2_TABLE8, |x: fi8| x.widen(), |x: fi16| round_half(x);
+    fi16, fi32, LOG2_TABLE16, |x: fi16| x.widen(), |x: fi32| round_half(x);
+    fi32, fi64, LOG2_TABLE32, |x: fi32| x.widen(), |x: fi64| round_half(x);
+    fi64, fi128, LOG2_TABLE64, |x: fi64| x.widen(), |x: fi128| round_half(x);
+    fi128, fi256, LOG2_TABLE128, |x: fi128| x.widen(), |x: fi256| round_half(x);
+);
@@ -1,6 +1,7 @@
 #![allow(clippy::reversed_empty_ranges)]
 
 use core::{
+    cmp::Ordering,
     fmt,
     iter::{Product, Sum},
     ops::*,
@@ -12,18 +13,19 @@ use core::{
 use awint::{
     Bits, InlAwi,
     awint_internals::{u256_div_rem, widening_mul_add_u128},
-    fp::{F32, F64, FP},
     inlawi_ty,
 };
 use fracints_internals::{impl_signed, *};
-#[cfg(feature = "serde")]
+#[cfg(feature = "serde_support")]
 use serde::{Deserialize, Serialize};
 
-use crate::{Fracint, FracintDouble, FracintHalf, constants::*, internal::*};
+use crate::{
+    Fracint, FracintDouble, FracintHalf, FracintSigned, RoundingMode, constants::*, internal::*,
+};
 
 macro_rules! sqrt_fast {
-    ($name:ident, $ty:ident, $n:expr, $truncate:tt, $widen:tt) => {
-        fn $name(mut s: $ty) -> $ty {
+    ($vis:vis $name:ident, $ty:ident, $n:expr, $truncate:tt, $widen:tt) => {
+        $vis fn $name(mut s: $ty) -> $ty {
             if s <= $ty::ZERO {
                 return $ty::ZERO
             }
@@ -54,8 +56,13 @@ macro_rules! sqrt_fast {
     };
 }
 
+/// `fi8` is small enough to use a full lookup table of the truncated square
+/// roots
 fn sqrt_fast_fi8(s: fi8) -> fi8 {
-    s.sqrt_slow()
+    if s <= fi8::ZERO {
+        return fi8::ZERO
+    }
+    SQRT_LUT8[s.0 as usize]
 }
 
 sqrt_fast!(sqrt_fast_fi16, fi16, 1, { |s: fi16| s }, { |f: fi16| f });
@@ -80,6 +87,14 @@ sqrt_fast!(
     { |f: fi16| f.widen().widen().widen() }
 );
 
+sqrt_fast!(
+    sqrt_fast_fi256,
+    fi256,
+    5,
+    { |s: fi256| s.truncate().truncate().truncate().truncate() },
+    { |f: fi16| f.widen().widen().widen().widen() }
+);
+
 macro_rules! impl_signed1 {
     ($(
         $ty:ident,
@@ -87,44 +102,61 @@ macro_rules! impl_signed1 {
         $iX:ident,
         $uX:ident,
         $iD:ident,
-        $to_string:ident,
-        $from_str:ident,
+        $tyD:ident,
+        $to_str_buf:ident,
+        $from_ascii:ident,
+        $str_len:ident,
         $sqrt_fast:ident,
         $n:expr,
-        $to_int:ident,
         $c:expr
     );*;) => {$(
         impl_signed!(
             $ty,
             $s,
             $iX,
-            $uX,
-            $to_string,
-            $from_str,
-            |a: $iX, b: $iX| (($iD::from(a) * $iD::from(b)) >> ($uX::BITS - 1)) as $iX,
-            |a: $iX, b: $iX| (($iD::from(a) << ($uX::BITS - 1)) / $iD::from(b)) as $iX,
+            $to_str_buf,
+            $from_ascii,
+            $str_len,
+            |a: $iX, b: $iX| {
+                let p = $iD::from(a) * $iD::from(b);
+                // the discarded bits are shifted to the top of a `u128`
+                let frac = u128::from(p as $uX) << (128 - ($uX::BITS - 1));
+                ((p >> ($uX::BITS - 1)) as $iX, frac)
+            },
+            RoundingMode::Floor,
+            |a: $iX, b: $iX| {
+                let (a, b) = ($iD::from(a) << ($uX::BITS - 1), $iD::from(b));
+                ((a / b) as $iX, (a % b) as $iX)
+            },
             $sqrt_fast,
+            |x: $ty| x.exp_neg_taylor(),
+            |x: $ty| x.ln_1p_taylor(),
             $n,
-            $to_int,
-            $c
+            $c,
+            |x: $ty| x.widen(),
+            |x: $tyD| round_half(x)
         );
     )*};
 }
 
 impl_signed1!(
-    fi8, "fi8", i8, u8, i16, i8_to_string, i8_from_str, sqrt_fast_fi8, 8, to_i8, CONST8;
-    fi16, "fi16", i16, u16, i32, i16_to_string, i16_from_str, sqrt_fast_fi16, 16, to_i16, CONST16;
-    fi32, "fi32", i32, u32, i64, i32_to_string, i32_from_str, sqrt_fast_fi32, 32, to_i32, CONST32;
-    fi64, "fi64", i64, u64, i128, i64_to_string, i64_from_str, sqrt_fast_fi64, 64, to_i64, CONST64;
+    fi8, "fi8", i8, u8, i16, fi16, i8_to_str_buf, i8_from_ascii, I8_STR_LEN,
+        sqrt_fast_fi8, 8, CONST8;
+    fi16, "fi16", i16, u16, i32, fi32, i16_to_str_buf, i16_from_ascii, I16_STR_LEN,
+        sqrt_fast_fi16, 16, CONST16;
+    fi32, "fi32", i32, u32, i64, fi64, i32_to_str_buf, i32_from_ascii, I32_STR_LEN,
+        sqrt_fast_fi32, 32, CONST32;
+    fi64, "fi64", i64, u64, i128, fi128, i64_to_str_buf, i64_from_ascii, I64_STR_LEN,
+        sqrt_fast_fi64, 64, CONST64;
 );
 // the 128 bit case needs special handling for the widening multiplies
 impl_signed!(
     fi128,
     "fi128",
     i128,
-    u128,
-    i128_to_string,
-    i128_from_str,
+    i128_to_str_buf,
+    i128_from_ascii,
+    I128_STR_LEN,
     |mut lhs: i128, mut rhs: i128| {
         let lhs_msb = lhs < 0;
         let rhs_msb = rhs < 0;
@@ -141,12 +173,18 @@ impl_signed!(
         // because of the shift, we retain one bit from `lo`
         // TODO see if we could optimize
         let mut res = (hi << 1) | (lo >> 127);
+        let mut frac = lo << 1;
 
         if lhs_msb != rhs_msb {
-            res = res.wrapping_neg();
+            // negate the whole 256 bit product so that it is truncated towards negative
+            // infinity like the smaller types
+            res = res.wrapping_neg().wrapping_sub((frac != 0) as u128);
+            frac = frac.wrapping_neg();
         }
-        res as i128
+        (res as i128, frac)
     },
+    // unlike the smaller types, products are truncated towards zero
+    RoundingMode::TowardZero,
     |mut lhs: i128, mut rhs: i128| {
         let lhs_msb = lhs < 0;
         let rhs_msb = rhs < 0;
@@ -161,27 +199,156 @@ impl_signed!(
 
         let lo = lhs << 127;
         let hi = lhs >> 1;
-        let (quo, _) = u256_div_rem((lo, hi), (rhs, 0));
+        let (quo, rem) = u256_div_rem((lo, hi), (rhs, 0));
         let mut quo = quo.0;
+        let mut rem = rem.0;
 
-        /*
         if lhs_msb {
             rem = rem.wrapping_neg();
         }
-        */
 
         if lhs_msb != rhs_msb {
             quo = quo.wrapping_neg();
         }
-        quo as i128
+        (quo as i128, rem as i128)
     },
     sqrt_fast_fi128,
+    |x: fi128| x.exp_neg_taylor(),
+    |x: fi128| x.ln_1p_taylor(),
     128,
-    to_i128,
-    CONST128
+    CONST128,
+    |x: fi128| x.widen(),
+    |x: fi256| round_half(x)
+);
+
+// the primitive integers stop at 128 bits, so `fi256` uses the `i256` defined
+// by `fracints_internals` and has no Taylor series constants
+impl_signed!(
+    /// This is the double width type of `fi128`, so that every signed fracint
+    /// implements `FracintDouble`. Multiplication and division are done with
+    /// `awint`, and there are no specialized functions such as
+    /// `cos_sin_pi_taylor`.
+    ///
+    /// ```
+    /// use fracints::*;
+    ///
+    /// let x = fi128!(0.75);
+    /// let y = fi128!(-0.3);
+    /// // the product is exact in the double width type
+    /// let p = x.saturating_widening_mul(y);
+    /// assert_eq!(p.truncate(), x.mul_with(y, RoundingMode::Floor));
+    /// assert_eq!(fi256::from(x).to_string(), "0.75");
+    /// assert_eq!(
+    ///     "1/3".parse::<fi256>().unwrap().to_string(),
+    ///     "0.33333333333333333333333333333333333333333333333333333333333333333333333333334"
+    /// );
+    ///
+    /// // `fi128` has `sqrt_slow` and can be used with `Accumulator`
+    /// assert_eq!(fi128!(0.25).sqrt_slow(), fi128!(0.5));
+    /// ```
+    fi256,
+    "fi256",
+    i256,
+    i256_to_str_buf,
+    i256_from_ascii,
+    I256_STR_LEN,
+    |lhs: i256, rhs: i256| {
+        let mut prod = <inlawi_ty!(512)>::zero();
+        prod.arb_imul_add_(&mut lhs.to_awi(), &mut rhs.to_awi());
+        let mut floor = <inlawi_ty!(256)>::zero();
+        floor.field_from(&prod, 255, 256).unwrap();
+        let mut frac = <inlawi_ty!(256)>::zero();
+        frac.field_to(1, &prod, 255).unwrap();
+        let frac = i256::from_bits(&frac);
+        (i256::from_bits(&floor), (frac.hi() as u128, frac.lo()))
+    },
+    RoundingMode::Floor,
+    |lhs: i256, rhs: i256| {
+        let mut duo = <inlawi_ty!(512)>::zero();
+        duo.sign_resize_(&lhs.to_awi());
+        duo.shl_(255).unwrap();
+        let mut div = <inlawi_ty!(512)>::zero();
+        div.sign_resize_(&rhs.to_awi());
+        let mut quo = <inlawi_ty!(512)>::zero();
+        let mut rem = <inlawi_ty!(512)>::zero();
+        Bits::idivide(&mut quo, &mut rem, &mut duo, &mut div).unwrap();
+        (i256::from_bits(&quo), i256::from_bits(&rem))
+    },
+    sqrt_fast_fi256,
+    // there is no larger type, so the series are evaluated directly with enough
+    // iterations for every term to be truncated to zero
+    |x: fi256| if x <= fi256::ZERO {
+        fi256::ONE
+    } else {
+        exp_neg_taylor_base(x, 64)
+    },
+    |x: fi256| {
+        let half = ln_1p_div2_taylor_base(x, 256);
+        half.saturating_add(half)
+    },
+    256
 );
 
-impl_signed_double!(fi8, fi16, i8, u8, i16, u16);
-impl_signed_double!(fi16, fi32, i16, u16, i32, u32);
-impl_signed_double!(fi32, fi64, i32, u32, i64, u64);
-impl_signed_double!(fi64, fi128, i64, u64, i128, u128);
+impl_signed_double!(fi8, fi16, i8, i16);
+impl_signed_double!(fi16, fi32, i16, i32);
+impl_signed_double!(fi32, fi64, i32, i64);
+impl_signed_double!(fi64, fi128, i64, i128);
+impl_signed_double!(fi128, fi256, i128, i256);
+
+macro_rules! impl_sqrt_round {
+    ($(
+        $ty:ident,
+        $iX:ident,
+        $ext:ident,
+        $isqrt:expr,
+        $ext_sqrt:expr,
+        $to_ext:expr,
+        $from_ext:expr
+    );*;) => {$(
+        impl $ty {
+            /// Calculates the square root rounded to the nearest value. Negative
+            /// inputs return `fiN::ZERO`.
+            ///
+            /// Ties are impossible, so the result is always within 0.5 ULP of
+            /// the true value. The square root of `fiN::ONE` is `fiN::ONE`.
+            pub fn sqrt_round(self) -> $ty {
+                if self <= $ty::ZERO {
+                    return $ty::ZERO
+                }
+                // `round(sqrt(x)) = (floor(sqrt(4*x)) + 1) / 2`
+                let s = ($isqrt)(self.0);
+                $ty(((s + 1) >> 1) as $iX)
+            }
+
+            /// Calculates `1/sqrt(self) - 1` for `self` in the range `[0.25, 1)`,
+            /// which is in the range `(0, 1]`. This saturates to `fiN::ONE` for
+            /// inputs less than 0.25 including nonpositive inputs.
+            ///
+            /// Multiplying by `1 + self.inv_sqrt_sub1()` can be used instead of
+            /// a division by a square root for normalization. The result is
+            /// within 1 ULP of the true value.
+            pub fn inv_sqrt_sub1(self) -> $ty {
+                if self <= $ty::ZERO {
+                    return $ty::ONE
+                }
+                let s = $ext_sqrt($to_ext(self));
+                $from_ext($ext::ONE.wrapping_sub(s).saturating_div(s))
+            }
+        }
+    )*};
+}
+
+impl_sqrt_round!(
+    fi8, i8, fi16, |x: i8| ((x as u16) << 9).isqrt(), fi16::sqrt_round,
+        |x: fi8| x.widen(), |x: fi16| round_half(x);
+    fi16, i16, fi32, |x: i16| ((x as u32) << 17).isqrt(), fi32::sqrt_round,
+        |x: fi16| x.widen(), |x: fi32| round_half(x);
+    fi32, i32, fi64, |x: i32| ((x as u64) << 33).isqrt(), fi64::sqrt_round,
+        |x: fi32| x.widen(), |x: fi64| round_half(x);
+    fi64, i64, fi128, |x: i64| ((x as u128) << 65).isqrt(), fi128::sqrt_round,
+        |x: fi64| x.widen(), |x: fi128| round_half(x);
+    // `fi256` does not have `sqrt_round`, but `sqrt_fast` is far more precise than
+    // the `fi128` result needs
+    fi128, i128, fi256, |x: i128| isqrt_u256_hi((x as u128) << 1), fi256::sqrt_fast,
+        |x: fi128| x.widen(), |x: fi256| round_half(x);
+);
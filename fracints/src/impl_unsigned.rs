@@ -0,0 +1,155 @@
+use core::{
+    cmp::Ordering,
+    fmt,
+    iter::{Product, Sum},
+    ops::*,
+    primitive::*,
+    result::Result,
+    str::FromStr,
+};
+
+use awint::{
+    Bits, InlAwi,
+    awint_internals::{u256_div_rem, widening_mul_add_u128},
+    inlawi_ty,
+};
+use fracints_internals::{impl_unsigned, *};
+#[cfg(feature = "serde_support")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Fracint, FracintDouble, FracintHalf, RoundingMode, fi16, fi32, fi64, fi128, fi256, internal::*,
+};
+
+macro_rules! impl_unsigned1 {
+    ($(
+        $ty:ident,
+        $s:expr,
+        $uX:ident,
+        $uD:ident,
+        $to_str_buf:ident,
+        $from_ascii:ident,
+        $str_len:ident,
+        $tyD:ident,
+        $n:expr,
+        $to_int:ident
+    );*;) => {$(
+        impl_unsigned!(
+            $ty,
+            $s,
+            $uX,
+            $to_str_buf,
+            $from_ascii,
+            $str_len,
+            |a: $uX, b: $uX| {
+                let p = $uD::from(a) * $uD::from(b);
+                ((p >> $uX::BITS) as $uX, p as $uX)
+            },
+            |a: $uX, b: $uX| {
+                let (a, b) = ($uD::from(a) << $uX::BITS, $uD::from(b));
+                ((a / b) as $uX, (a % b) as $uX)
+            },
+            // the double width integer square root is exact and fast enough
+            |s: $ty| $ty((($uD::from(s.0) << $uX::BITS).isqrt()) as $uX),
+            // the signed functions of the double width type are far more precise
+            // than needed
+            |x: $ty| round_to_unsigned_half(to_signed_double::<_, $tyD>(x).exp_neg()),
+            |x: $ty| round_to_unsigned_half(to_signed_double::<_, $tyD>(x).ln_1p()),
+            $n,
+            $to_int
+        );
+    )*};
+}
+
+impl_unsigned1!(
+    fu8, "fu8", u8, u16, u8_to_str_buf, u8_from_ascii, U8_STR_LEN, fi16, 8, to_u8;
+    fu16, "fu16", u16, u32, u16_to_str_buf, u16_from_ascii, U16_STR_LEN, fi32, 16, to_u16;
+    fu32, "fu32", u32, u64, u32_to_str_buf, u32_from_ascii, U32_STR_LEN, fi64, 32, to_u32;
+    fu64, "fu64", u64, u128, u64_to_str_buf, u64_from_ascii, U64_STR_LEN, fi128, 64, to_u64;
+);
+// the 128 bit case needs special handling for the widening multiplies
+impl_unsigned!(
+    fu128,
+    "fu128",
+    u128,
+    u128_to_str_buf,
+    u128_from_ascii,
+    U128_STR_LEN,
+    |lhs: u128, rhs: u128| {
+        let (lo, hi) = widening_mul_add_u128(lhs, rhs, 0);
+        (hi, lo)
+    },
+    |lhs: u128, rhs: u128| {
+        let (quo, rem) = u256_div_rem((0, lhs), (rhs, 0));
+        (quo.0, rem.0)
+    },
+    |s: fu128| fu128(isqrt_u256_hi(s.0)),
+    |x: fu128| round_to_unsigned_half(to_signed_double::<_, fi256>(x).exp_neg()),
+    |x: fu128| round_to_unsigned_half(to_signed_double::<_, fi256>(x).ln_1p()),
+    128,
+    to_u128
+);
+
+impl_unsigned_double!(fu8, fu16, u8, u16);
+impl_unsigned_double!(fu16, fu32, u16, u32);
+impl_unsigned_double!(fu32, fu64, u32, u64);
+impl_unsigned_double!(fu64, fu128, u64, u128);
+
+macro_rules! impl_sqrt_round {
+    ($(
+        $ty:ident,
+        $ext:ident,
+        $isqrt_round:expr,
+        $ext_sqrt:expr
+    );*;) => {$(
+        impl $ty {
+            /// Calculates the square root rounded to the nearest value.
+            ///
+            /// Ties are impossible, so the result is always within 0.5 ULP of
+            /// the true value, except that the square roots of values close
+            /// enough to one saturate to `fuN::ONE`.
+            pub fn sqrt_round(self) -> $ty {
+                $ty(($isqrt_round)(self.0))
+            }
+
+            /// Calculates `1/sqrt(self) - 1` for `self` in the range `[0.25, 1)`,
+            /// which is in the range `(0, 1]`. This saturates to `fuN::ONE` for
+            /// inputs less than 0.25.
+            ///
+            /// Multiplying by `1 + self.inv_sqrt_sub1()` can be used instead of
+            /// a division by a square root for normalization. The result is
+            /// within 1 ULP of the true value.
+            pub fn inv_sqrt_sub1(self) -> $ty {
+                if self.is_zero() {
+                    return $ty::ONE
+                }
+                // calculated in the signed double width type
+                let s = $ext_sqrt(to_signed_double::<_, $ext>(self));
+                round_to_unsigned_half($ext::ONE.wrapping_sub(s).saturating_div(s))
+            }
+        }
+    )*};
+}
+
+impl_sqrt_round!(
+    // `round(sqrt(x)) = (floor(sqrt(4*x)) + 1) / 2`, saturating at one
+    fu8, fi16, |x: u8| u8::try_from(((u32::from(x) << 10).isqrt() + 1) >> 1)
+        .unwrap_or(u8::MAX), fi16::sqrt_round;
+    fu16, fi32, |x: u16| u16::try_from(((u64::from(x) << 18).isqrt() + 1) >> 1)
+        .unwrap_or(u16::MAX), fi32::sqrt_round;
+    fu32, fi64, |x: u32| u32::try_from(((u128::from(x) << 34).isqrt() + 1) >> 1)
+        .unwrap_or(u32::MAX), fi64::sqrt_round;
+    // `4*x` does not fit, instead the truncated `s = floor(sqrt(x))` is rounded up
+    // if `(s + 1/2)^2 < x`, which for integers is `s*(s + 1) < x`
+    fu64, fi128, |x: u64| {
+        let x = u128::from(x) << 64;
+        let s = x.isqrt();
+        u64::try_from(s + u128::from(s * (s + 1) < x)).unwrap_or(u64::MAX)
+    }, fi128::sqrt_round;
+    fu128, fi256, |x: u128| {
+        // `x` is shifted up by 128 bits, so only the high half of the product matters
+        let s = isqrt_u256_hi(x);
+        let (_, hi) = widening_mul_add_u128(s, s, s);
+        if hi < x { s.saturating_add(1) } else { s }
+    }, fi256::sqrt_fast;
+);
@@ -1,3 +1,4 @@
+use awint::awint_internals::u256_div_rem;
 use fracints_internals::traits::*;
 
 use crate::fi16;
@@ -26,7 +27,7 @@ pub fn eval_simple_isqrt_lut(lut: &[fi16], cutoff: fi16, bits: usize, x: fi16) -
     if (inx1 as usize) < lut.len() {
         let y1 = lut[inx1 as usize];
         // (y1 - y0)*t + y0
-        y1.wrapping_sub(y0).wrapping_mul(rem_inx).wrapping_add(y0)
+        y1.wrapping_sub(y0).wrapping_mul_add(rem_inx, y0)
     } else {
         // y0 - (y0*t)
         y0.wrapping_sub(y0.wrapping_mul(rem_inx))
@@ -37,17 +38,192 @@ pub fn goldschmidt<F: Fracint>(s: F, f: F, n: usize) -> F {
     let half = (F::ONE >> 1).wrapping_add(F::ULP);
 
     let mut r;
-    let mut g = f.wrapping_mul(s).wrapping_add(s);
+    let mut g = f.wrapping_mul_add(s, s);
     let mut h = half.wrapping_add(f >> 1);
 
     for i in 0..n {
         r = half.wrapping_sub(g.wrapping_mul(h));
-        g = r.wrapping_mul(g).wrapping_add(g);
+        g = r.wrapping_mul_add(g, g);
         if i == (n - 1) {
             // don't need the last step
             break
         }
-        h = r.wrapping_mul(h).wrapping_add(h);
+        h = r.wrapping_mul_add(h, h);
     }
     g
 }
+
+/// Returns the truncated square root of `x * 2^128`, which is the square root
+/// operation for `fu128`
+pub fn isqrt_u256_hi(x: u128) -> u128 {
+    if x == 0 {
+        return 0
+    }
+    // start with an overestimate and use Newton's method, which is monotonically
+    // decreasing until it reaches the truncated square root
+    let s = x.isqrt();
+    let mut r = if s == u64::MAX as u128 {
+        u128::MAX
+    } else {
+        (s + 1) << 64
+    };
+    loop {
+        let ((q, q_hi), _) = u256_div_rem((0, x), (r, 0));
+        if q_hi != 0 {
+            // only possible when `r == u128::MAX` is already the result
+            break r
+        }
+        let next = (r >> 1) + (q >> 1) + (r & q & 1);
+        if next >= r {
+            break r
+        }
+        r = next;
+    }
+}
+
+// trigonometry, the constants are calculated by `gen_constants.rs` in the
+// testcrate
+
+fn int_of<F: Fracint>(x: usize) -> F::Int {
+    F::Int::try_from(x as u128).unwrap()
+}
+
+/// Rounds to the nearest value of the half sized type. Numerical -1.0 is
+/// mapped to `NEG_ONE` instead of `MIN`.
+pub fn round_half<F: FracintHalf>(x: F) -> F::Half {
+    let half = F::from(F::Half::ULP) >> 1;
+    let res = x.saturating_add(half).truncate();
+    if res == F::Half::MIN {
+        F::Half::NEG_ONE
+    } else {
+        res
+    }
+}
+
+/// Converts an unsigned fracint exactly to the signed fracint `S` of twice the
+/// width
+pub fn to_signed_double<U: Fracint, S: Fracint>(x: U) -> S
+where
+    S::Int: From<U::Int>,
+{
+    S::from_int(S::Int::from(x.as_int())) << (U::BITS - 1)
+}
+
+/// Rounds a nonnegative signed fracint to the nearest value of the unsigned
+/// fracint `U` of half the width, saturating to `U::ONE`
+pub fn round_to_unsigned_half<S: Fracint, U: Fracint>(x: S) -> U
+where
+    U::Int: TryFrom<S::Int>,
+{
+    let x = (x >> (U::BITS - 2)).wrapping_add(S::ULP) >> 1;
+    U::Int::try_from(x.as_int()).map_or(U::ONE, U::from_int)
+}
+
+/// Calculates `cos((tau/4) * t)` for `-0.5 <= t <= 0.5`
+pub fn cos_taudiv4_taylor_base<F: Fracint>(t: F, num_4divtau_sqr: F, iters: usize) -> F {
+    // This is based on the equation
+    // cos(theta) = 1 - theta^2/2! + theta^4/4! - theta^6/6! + ...
+    // where `theta = t / (4/tau)`. We compute `1 - cos(theta)` and then subtract
+    // from one at the end so that everything stays in range.
+    let theta_sqr = t.wrapping_mul(t).saturating_div(num_4divtau_sqr);
+    let mut term = theta_sqr >> 1;
+    let mut sum = term;
+    let mut factorial_num = 2;
+    for i in 0..iters {
+        term = term
+            .wrapping_mul(theta_sqr)
+            .saturating_div_int(int_of::<F>((factorial_num + 1) * (factorial_num + 2)));
+        factorial_num += 2;
+        if term.is_zero() {
+            break
+        }
+        if (i & 0b1) == 0 {
+            sum = sum.wrapping_sub(term);
+        } else {
+            sum = sum.wrapping_add(term);
+        }
+    }
+    F::ONE.wrapping_sub(sum)
+}
+
+/// Calculates `sin((tau/4) * t)` for `-0.5 <= t <= 0.5`
+pub fn sin_taudiv4_taylor_base<F: Fracint>(
+    t: F,
+    num_4divtau: F,
+    num_4divtau_sqr: F,
+    iters: usize,
+) -> F {
+    // This is based on the equation
+    // sin(theta) = theta - theta^3/3! + theta^5/5! - theta^7/7! + ...
+    // where `theta = t / (4/tau)`. Sine is odd, and we calculate with the
+    // absolute value so that truncation is symmetric.
+    let neg = t.is_negative();
+    let t = t.wrapping_abs();
+    let theta = t.saturating_div(num_4divtau);
+    let theta_sqr = t.wrapping_mul(t).saturating_div(num_4divtau_sqr);
+    let mut term = theta;
+    let mut sum = term;
+    let mut factorial_num = 1;
+    for i in 0..iters {
+        term = term
+            .wrapping_mul(theta_sqr)
+            .saturating_div_int(int_of::<F>((factorial_num + 1) * (factorial_num + 2)));
+        factorial_num += 2;
+        if term.is_zero() {
+            break
+        }
+        if (i & 0b1) == 0 {
+            sum = sum.wrapping_sub(term);
+        } else {
+            sum = sum.wrapping_add(term);
+        }
+    }
+    if neg { sum.wrapping_neg() } else { sum }
+}
+
+// exponentials and logarithms
+
+/// Calculates `e^(-x)` for `0 <= x <= 1`
+pub fn exp_neg_taylor_base<F: Fracint>(x: F, iters: usize) -> F {
+    // This is based on the equation
+    // 1 - e^(-x) = x - x^2/2! + x^3/3! - x^4/4! + ...
+    // which keeps every partial sum in range, and then we subtract from one at
+    // the end.
+    let mut term = x;
+    let mut sum = term;
+    for i in 0..iters {
+        term = term.wrapping_mul(x).saturating_div_int(int_of::<F>(i + 2));
+        if term.is_zero() {
+            break
+        }
+        if (i & 0b1) == 0 {
+            sum = sum.wrapping_sub(term);
+        } else {
+            sum = sum.wrapping_add(term);
+        }
+    }
+    F::ONE.wrapping_sub(sum)
+}
+
+/// Calculates `ln(1 + x) / 2`, saturating if it is less than -0.5
+pub fn ln_1p_div2_taylor_base<F: Fracint>(x: F, iters: usize) -> F {
+    // This is based on the equation
+    // ln(1 + x) / 2 = atanh(w) = w + w^3/3 + w^5/5 + w^7/7 + ...
+    // where `w = x / (2 + x) = (x / 4) / (1 / 2 + x / 4)`. The terms are
+    // decreasing by at least `w^2 <= 1/4` as long as the result is in range.
+    let quarter_x = x >> 2;
+    let half = F::ULP << (F::BITS - 2);
+    let w = quarter_x.saturating_div(half.wrapping_add(quarter_x));
+    let w_sqr = w.wrapping_mul(w);
+    let mut pow = w;
+    let mut sum = w;
+    for i in 0..iters {
+        pow = pow.wrapping_mul(w_sqr);
+        let term = pow.saturating_div_int(int_of::<F>(2 * i + 3));
+        if term.is_zero() {
+            break
+        }
+        sum = sum.saturating_add(term);
+    }
+    sum
+}
@@ -9,19 +9,36 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+mod accumulator;
+mod angle;
 mod constants;
+mod cordic;
+mod exp_log;
 mod impl_signed;
+mod impl_unsigned;
 #[doc(hidden)]
 pub mod internal;
+#[cfg(feature = "serde_support")]
+pub mod serde_helpers;
 
-pub use fracints_internals::{FracintSerdeError, traits::*};
+pub use fracints_internals::{FracintBound, FracintParseError, FracintSerdeError, i256, traits::*};
 pub use fracints_macros::*;
 
-pub use crate::impl_signed::*;
+pub use crate::{
+    accumulator::{Accumulator, dot},
+    angle::Angle,
+    impl_signed::*,
+    impl_unsigned::*,
+};
 
 pub mod prelude {
-    pub use fracints_internals::traits::*;
+    pub use fracints_internals::{i256, traits::*};
     pub use fracints_macros::*;
 
-    pub use crate::impl_signed::*;
+    pub use crate::{
+        accumulator::{Accumulator, dot},
+        angle::Angle,
+        impl_signed::*,
+        impl_unsigned::*,
+    };
 }
@@ -0,0 +1,111 @@
+//! Helper modules for choosing the representation of fracints with
+//! `#[serde(with = "...")]`. The default representation of the fracint types
+//! is the same as [raw].
+//!
+//! ```
+//! use fracints::prelude::*;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Filter {
+//!     #[serde(with = "fracints::serde_helpers::raw")]
+//!     a: fi16,
+//!     #[serde(with = "fracints::serde_helpers::decimal")]
+//!     b: fi32,
+//!     #[serde(with = "fracints::serde_helpers::float")]
+//!     c: fi64,
+//! }
+//! ```
+
+/// Serializes as the raw underlying integer (e.g. `fi16!(0.5)` is serialized as
+/// `16384`). This is exact and the most compact.
+pub mod raw {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::Fracint;
+
+    pub fn serialize<F: Fracint + Serialize, S: Serializer>(
+        x: &F,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        x.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, F: Fracint + Deserialize<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<F, D::Error> {
+        F::deserialize(deserializer)
+    }
+}
+
+/// Serializes as a decimal string (e.g. `fi16!(0.5)` is serialized as
+/// `"0.5"`), using the `Display` and `FromStr` implementations which round
+/// trip exactly. The one exception is that `fiN::MIN` is displayed as "-1.0"
+/// and is deserialized as `fiN::NEG_ONE`.
+pub mod decimal {
+    use core::{fmt, marker::PhantomData, str::FromStr};
+
+    use serde::{
+        Deserializer, Serializer,
+        de::{self, Visitor},
+    };
+
+    use crate::Fracint;
+
+    pub fn serialize<F: Fracint, S: Serializer>(x: &F, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(x)
+    }
+
+    struct DecimalVisitor<F>(PhantomData<F>);
+
+    impl<F: Fracint> Visitor<'_> for DecimalVisitor<F>
+    where
+        <F as FromStr>::Err: fmt::Display,
+    {
+        type Value = F;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a string representing a fracint")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<F, E> {
+            F::from_str(v).map_err(E::custom)
+        }
+    }
+
+    pub fn deserialize<'de, F: Fracint, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<F, D::Error>
+    where
+        <F as FromStr>::Err: fmt::Display,
+    {
+        deserializer.deserialize_str(DecimalVisitor(PhantomData))
+    }
+}
+
+/// Serializes as an `f64` (e.g. `fi16!(0.5)` is serialized as `0.5`). This is
+/// convenient for human readable formats, but note that `fi64` and `fi128`
+/// have more precision than `f64` and will lose precision. Deserialization
+/// truncates the same way as [Fracint::from_f64] and returns an error for
+/// values outside of `[-1.0, 1.0]`.
+pub mod float {
+    use serde::{Deserialize, Deserializer, Serializer, de};
+
+    use crate::Fracint;
+
+    pub fn serialize<F: Fracint, S: Serializer>(x: &F, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(x.to_f64())
+    }
+
+    pub fn deserialize<'de, F: Fracint, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<F, D::Error> {
+        let f = f64::deserialize(deserializer)?;
+        F::from_f64(f).ok_or_else(|| {
+            de::Error::invalid_value(
+                de::Unexpected::Float(f),
+                &"a float in the range [-1.0, 1.0]",
+            )
+        })
+    }
+}
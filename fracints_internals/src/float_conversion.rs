@@ -0,0 +1,111 @@
+use core::cmp::max;
+
+use awint::{Bits, InlAwi};
+
+/// Assigns the truncated magnitude of `mantissa * 2^exponent` to `x`, which
+/// has `fp` fraction bits, and then negates `x` if `sign` is set. The value
+/// must have an absolute value less than one.
+fn ieee_to_fixed(x: &mut Bits, fp: usize, sign: bool, mantissa: u64, exponent: isize) {
+    x.zero_();
+    let shift = exponent.wrapping_add(fp as isize);
+    if shift < 0 {
+        if let Some(mantissa) = mantissa.checked_shr(shift.unsigned_abs() as u32) {
+            x.u64_(mantissa);
+        }
+    } else {
+        // the value is less than one, so this is always in range
+        x.u64_(mantissa);
+        x.shl_(shift as usize).unwrap();
+    }
+    x.neg_(sign);
+}
+
+/// Assigns the value of `f` to `x` with `fp` fraction bits, using truncation
+/// rounding. `f` must have an absolute value less than one, infinities and NaN
+/// are cast to zero.
+pub fn f32_to_fixed(x: &mut Bits, fp: usize, f: f32) {
+    let exponent = ((f.to_bits() >> 23) & ((1 << 8) - 1)) as isize;
+    let sign = (f.to_bits() >> 31) != 0;
+    let mantissa = (f.to_bits() & ((1 << 23) - 1)) as u64;
+    if exponent == 0 {
+        // subnormal
+        ieee_to_fixed(x, fp, sign, mantissa, -149)
+    } else if exponent != ((1 << 8) - 1) {
+        ieee_to_fixed(x, fp, sign, mantissa | (1 << 23), exponent - 150)
+    } else {
+        x.zero_()
+    }
+}
+
+/// Assigns the value of `f` to `x` with `fp` fraction bits, using truncation
+/// rounding. `f` must have an absolute value less than one, infinities and NaN
+/// are cast to zero.
+pub fn f64_to_fixed(x: &mut Bits, fp: usize, f: f64) {
+    let exponent = ((f.to_bits() >> 52) & ((1 << 11) - 1)) as isize;
+    let sign = (f.to_bits() >> 63) != 0;
+    let mantissa = f.to_bits() & ((1 << 52) - 1);
+    if exponent == 0 {
+        // subnormal
+        ieee_to_fixed(x, fp, sign, mantissa, -1074)
+    } else if exponent != ((1 << 11) - 1) {
+        ieee_to_fixed(x, fp, sign, mantissa | (1 << 52), exponent - 1075)
+    } else {
+        x.zero_()
+    }
+}
+
+/// Returns the sign and the IEEE-754 bits without the sign bit of `x` with
+/// `fp` fraction bits, for a format with `m` explicit mantissa bits and an
+/// exponent `bias`. Uses truncation rounding, `x` must be small enough to not
+/// overflow the exponent.
+fn fixed_to_ieee(x: &mut Bits, signed: bool, fp: usize, m: usize, bias: isize) -> (bool, u64) {
+    if x.is_zero() {
+        return (false, 0)
+    }
+    let sign = signed && x.msb();
+    // reinterpret as unsigned to avoid imin overflow
+    x.neg_(sign);
+    let sig = x.sig();
+    // the exponent of the most significant numerical bit
+    let msnb = (sig as isize).wrapping_sub(1).wrapping_sub(fp as isize);
+    // the exponent of the least significant mantissa bit (subnormals have less)
+    let min_normal = 1 - bias;
+    let lsb = max(msnb - (m as isize), min_normal - (m as isize));
+    let res = if msnb < lsb {
+        // less than what subnormals can represent
+        (false, 0)
+    } else {
+        let shift = lsb.wrapping_add(fp as isize);
+        let mantissa = if shift < 0 {
+            // there are fewer significant bits than mantissa bits
+            x.to_u64() << shift.unsigned_abs()
+        } else {
+            let mut mantissa = InlAwi::from_u64(0);
+            let shift = shift as usize;
+            mantissa.field_from(x, shift, sig - shift).unwrap();
+            mantissa.to_u64()
+        };
+        if msnb < min_normal {
+            (sign, mantissa)
+        } else {
+            let exponent = (msnb + bias) as u64;
+            (sign, (mantissa & ((1 << m) - 1)) | (exponent << m))
+        }
+    };
+    x.neg_(sign);
+    res
+}
+
+/// Returns the IEEE-754 32 bit floating point value of `x` with `fp` fraction
+/// bits, using truncation rounding. `x` is reinterpreted as signed if `signed`.
+pub fn fixed_to_f32(x: &mut Bits, signed: bool, fp: usize) -> f32 {
+    let (sign, bits) = fixed_to_ieee(x, signed, fp, 23, 127);
+    f32::from_bits((bits as u32) | ((sign as u32) << 31))
+}
+
+/// Returns the IEEE-754 64 bit floating point value of `x` with `fp` fraction
+/// bits, using truncation rounding. `x` is reinterpreted as signed if `signed`.
+pub fn fixed_to_f64(x: &mut Bits, signed: bool, fp: usize) -> f64 {
+    let (sign, bits) = fixed_to_ieee(x, signed, fp, 52, 1023);
+    f64::from_bits(bits | ((sign as u64) << 63))
+}
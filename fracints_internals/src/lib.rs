@@ -1,13 +1,20 @@
+#![no_std]
 #![allow(clippy::manual_range_contains)]
 #![allow(clippy::comparison_chain)]
 
-// TODO use a specialized optimized version of the parser instead of pulling the
-// full `awint` dependency, but do fuzz against `awint` in tests
-
+mod float_conversion;
+#[cfg(feature = "reference")]
+#[doc(hidden)]
+pub mod reference;
 mod signed_macro;
 mod signed_macro2;
 mod str_conversion;
 pub mod traits;
+mod unsigned_macro;
+mod unsigned_macro2;
+mod wide;
 
 pub use awint;
+pub use float_conversion::*;
 pub use str_conversion::*;
+pub use wide::{FracintInt, WrappingFrom, i256};
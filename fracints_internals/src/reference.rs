@@ -0,0 +1,452 @@
+//! High precision reference values calculated with `awint`. These are slow and
+//! allocating, but are simple enough to be obviously correct. This is used by
+//! the testcrate and for evaluating constant expressions in the macros, and is
+//! behind the "reference" feature so that the runtime does not need `alloc`.
+
+use core::num::NonZeroUsize;
+
+use awint::{Awi, Bits};
+
+use crate::{
+    FracintSerdeError,
+    str_conversion::{FractionBits, round_to_even},
+    traits::Fracint,
+};
+
+/// The number of integer bits (including the sign bit) kept above the fixed
+/// point
+pub const INT_BITS: usize = 64;
+
+fn nzbw(w: usize) -> NonZeroUsize {
+    NonZeroUsize::new(w).unwrap()
+}
+
+/// Converts a nonnegative fracint to its unsigned integer, in 128 bit chunks
+/// since `F::Int` can be wider than a `u128`
+pub fn mag_to_awi<F: Fracint>(mag: F) -> Awi {
+    let chunks = F::BITS.div_ceil(128);
+    let mut res = Awi::zero(nzbw(chunks * 128));
+    for i in 0..chunks {
+        let mut chunk = mag >> (i * 128);
+        if (i + 1) < chunks {
+            chunk &= F::from_int(u128::MAX.try_into().unwrap());
+        }
+        let chunk: u128 = chunk.as_int().try_into().unwrap();
+        res.field_to(i * 128, &Awi::from_u128(chunk), 128).unwrap();
+    }
+    res
+}
+
+/// The inverse of [mag_to_awi], the bits of `x` above `F::BITS` should be zero
+pub fn mag_from_awi<F: Fracint>(x: &Bits) -> F {
+    let chunks = F::BITS.div_ceil(128);
+    let mut res = F::ZERO;
+    for i in (0..chunks).rev() {
+        let mut chunk = x.to_u128();
+        if i > 0 {
+            let mut tmp = Awi::from_u128(0);
+            tmp.field_from(x, i * 128, 128.min(x.bw() - (i * 128)))
+                .unwrap();
+            chunk = tmp.to_u128();
+        }
+        if (i + 1) < chunks {
+            res <<= 128;
+        }
+        res |= F::from_int(chunk.try_into().unwrap());
+    }
+    res
+}
+
+/// A signed fixed point number with `fp` fractional bits
+#[derive(Debug, Clone)]
+pub struct Ref {
+    pub awi: Awi,
+    pub fp: usize,
+}
+
+impl Ref {
+    pub fn zero(fp: usize) -> Self {
+        Self {
+            awi: Awi::zero(nzbw(fp + INT_BITS)),
+            fp,
+        }
+    }
+
+    pub fn from_i128(x: i128, fp: usize) -> Self {
+        let mut res = Self::zero(fp);
+        res.awi.sign_resize_(&Awi::from_i128(x));
+        res.awi.shl_(fp).unwrap();
+        res
+    }
+
+    /// Exact conversion from a fracint
+    pub fn from_fracint<F: Fracint>(x: F, fp: usize) -> Self {
+        let frac_bits = F::BITS - (F::SIGNED as usize);
+        assert!(frac_bits <= fp);
+        if F::SIGNED && (x == F::MIN) {
+            return Self::from_i128(-1, fp)
+        }
+        let mut res = Self::zero(fp);
+        res.awi.zero_resize_(&mag_to_awi(x.wrapping_abs()));
+        res.awi.shl_(fp - frac_bits).unwrap();
+        res.awi.neg_(x.is_negative());
+        res
+    }
+
+    /// Rounds to the nearest fracint, saturating to the numeric bounds
+    pub fn to_fracint<F: Fracint>(&self) -> F {
+        let frac_bits = F::BITS - (F::SIGNED as usize);
+        let shift = self.fp - frac_bits;
+        let mut tmp = self.awi.clone();
+        let neg = tmp.msb();
+        tmp.neg_(neg);
+        // round half up on the magnitude
+        let mut half = Awi::zero(tmp.nzbw());
+        half.set(shift - 1, true).unwrap();
+        tmp.add_(&half).unwrap();
+        tmp.lshr_(shift).unwrap();
+        let mag = if tmp.sig() > frac_bits {
+            F::ONE
+        } else {
+            mag_from_awi(&tmp)
+        };
+        if neg {
+            if F::SIGNED {
+                mag.wrapping_neg()
+            } else if mag.is_zero() {
+                F::ZERO
+            } else {
+                panic!("negative value cannot be converted to an unsigned fracint")
+            }
+        } else {
+            mag
+        }
+    }
+
+    /// Rounds to even to `fp` fraction bits (up to `F::BITS`), returning the
+    /// sign and the magnitude or `None` if the magnitude rounds to exactly one.
+    /// This rounds the same way as parsing a string of the same exact value.
+    pub fn to_fraction<F: FractionBits>(
+        &self,
+        fp: usize,
+    ) -> Result<(bool, Option<F>), FracintSerdeError> {
+        assert!((1..=F::BITS).contains(&fp) && (fp < self.fp));
+        let mag = self.abs().awi;
+        let shift = self.fp - fp;
+        let mut tmp = mag.clone();
+        tmp.lshr_(shift).unwrap();
+        // the fraction bits in 64 bit chunks from the most significant
+        let mut frac = F::ZERO;
+        for i in (0..fp.div_ceil(64)).rev() {
+            let mut chunk = Awi::from_u64(0);
+            chunk
+                .field_from(&tmp, i * 64, 64.min(fp - (i * 64)))
+                .unwrap();
+            frac = frac.shl_or(64, chunk.to_u128());
+        }
+        tmp.lshr_(fp).unwrap();
+        if tmp.sig() > 1 {
+            return Err(FracintSerdeError::Overflow)
+        }
+        let round = mag.get(shift - 1).unwrap();
+        let sticky = mag.tz() < (shift - 1);
+        round_to_even(tmp.to_u8(), frac, round, sticky, fp).map(|x| (self.is_negative(), x))
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.awi.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.awi.msb()
+    }
+
+    pub fn neg(&self) -> Self {
+        let mut res = self.clone();
+        res.awi.neg_(true);
+        res
+    }
+
+    pub fn abs(&self) -> Self {
+        let mut res = self.clone();
+        res.awi.abs_();
+        res
+    }
+
+    pub fn add(&self, rhs: &Self) -> Self {
+        let mut res = self.clone();
+        res.awi.add_(&rhs.awi).unwrap();
+        res
+    }
+
+    pub fn sub(&self, rhs: &Self) -> Self {
+        let mut res = self.clone();
+        res.awi.sub_(&rhs.awi).unwrap();
+        res
+    }
+
+    /// Multiplication truncated toward negative infinity
+    pub fn mul(&self, rhs: &Self) -> Self {
+        let w = self.awi.bw();
+        let mut lhs = self.awi.clone();
+        let mut rhs = rhs.awi.clone();
+        let mut prod = Awi::zero(nzbw(2 * w));
+        prod.arb_imul_add_(&mut lhs, &mut rhs);
+        prod.ashr_(self.fp).unwrap();
+        let mut res = Self::zero(self.fp);
+        res.awi.sign_resize_(&prod);
+        res
+    }
+
+    /// Division truncated toward zero
+    pub fn div(&self, rhs: &Self) -> Self {
+        let w = self.awi.bw();
+        let neg = self.is_negative() != rhs.is_negative();
+        let mut duo = Awi::zero(nzbw(2 * w));
+        duo.zero_resize_(&self.abs().awi);
+        duo.shl_(self.fp).unwrap();
+        let mut div = Awi::zero(nzbw(2 * w));
+        div.zero_resize_(&rhs.abs().awi);
+        let mut quo = Awi::zero(nzbw(2 * w));
+        let mut rem = Awi::zero(nzbw(2 * w));
+        Bits::udivide(&mut quo, &mut rem, &duo, &div).unwrap();
+        let mut res = Self::zero(self.fp);
+        res.awi.zero_resize_(&quo);
+        res.awi.neg_(neg);
+        res
+    }
+
+    /// Division by an integer truncated toward zero
+    pub fn div_int(&self, rhs: usize) -> Self {
+        let mut res = self.clone();
+        let neg = res.is_negative();
+        res.awi.neg_(neg);
+        res.awi.digit_udivide_inplace_(rhs).unwrap();
+        res.awi.neg_(neg);
+        res
+    }
+
+    /// Calculates pi using Machin's formula
+    pub fn pi(fp: usize) -> Self {
+        // atan(1/n) = 1/n - 1/(3*n^3) + 1/(5*n^5) - ...
+        let atan_inv = |n: usize| {
+            let mut sum = Self::zero(fp);
+            let mut pow = Self::from_i128(1, fp).div_int(n);
+            let mut i = 0;
+            loop {
+                let term = pow.div_int(2 * i + 1);
+                if term.is_zero() {
+                    break sum
+                }
+                if (i & 1) == 0 {
+                    sum = sum.add(&term);
+                } else {
+                    sum = sum.sub(&term);
+                }
+                pow = pow.div_int(n * n);
+                i += 1;
+            }
+        };
+        // pi = 16*atan(1/5) - 4*atan(1/239)
+        let mut res = atan_inv(5).sub(&atan_inv(239).div_int(4));
+        res.awi.shl_(4).unwrap();
+        res
+    }
+
+    /// Calculates `(cos(self), sin(self))` where `self` is in radians
+    pub fn cos_sin(&self) -> (Self, Self) {
+        let neg = self.is_negative();
+        let theta = self.abs();
+        let mut cos = Self::zero(self.fp);
+        let mut sin = Self::zero(self.fp);
+        // alternates between the cosine and sine terms
+        let mut term = Self::from_i128(1, self.fp);
+        let mut i = 0;
+        loop {
+            if term.is_zero() {
+                break
+            }
+            let add = (i & 0b10) == 0;
+            let target = if (i & 1) == 0 { &mut cos } else { &mut sin };
+            *target = if add {
+                target.add(&term)
+            } else {
+                target.sub(&term)
+            };
+            i += 1;
+            term = term.mul(&theta).div_int(i);
+        }
+        if neg { (cos, sin.neg()) } else { (cos, sin) }
+    }
+
+    /// Square root using Newton's method, panics on negative inputs
+    pub fn sqrt(&self) -> Self {
+        assert!(!self.is_negative());
+        if self.is_zero() {
+            return self.clone()
+        }
+        // start with a power of two overestimate, Newton's method is
+        // monotonically decreasing from there
+        let mut r = Self::zero(self.fp);
+        r.awi.uone_();
+        r.awi.shl_((self.awi.sig() + self.fp).div_ceil(2)).unwrap();
+        loop {
+            let mut next = r.add(&self.div(&r));
+            next.awi.ashr_(1).unwrap();
+            if next.awi.ige(&r.awi).unwrap() {
+                break r
+            }
+            r = next;
+        }
+    }
+
+    /// Arctangent in radians
+    pub fn atan(&self) -> Self {
+        let one = Self::from_i128(1, self.fp);
+        let mut x = self.clone();
+        // use `atan(x) = 2*atan(x / (1 + sqrt(1 + x^2)))` to reduce the argument
+        // for faster convergence
+        let mut doublings = 0;
+        let mut half = one.clone();
+        half.awi.ashr_(1).unwrap();
+        while x.abs().awi.igt(&half.awi).unwrap() {
+            let den = one.add(&one.add(&x.mul(&x)).sqrt());
+            x = x.div(&den);
+            doublings += 1;
+        }
+        // atan(x) = x - x^3/3 + x^5/5 - ...
+        let x_sqr = x.mul(&x);
+        let mut sum = Self::zero(self.fp);
+        let mut pow = x;
+        let mut i = 0;
+        loop {
+            let term = pow.div_int(2 * i + 1);
+            if term.is_zero() {
+                break
+            }
+            if (i & 1) == 0 {
+                sum = sum.add(&term);
+            } else {
+                sum = sum.sub(&term);
+            }
+            pow = pow.mul(&x_sqr);
+            i += 1;
+        }
+        sum.awi.shl_(doublings).unwrap();
+        sum
+    }
+
+    /// The four quadrant arctangent of `y / x` in radians, `pi` is passed in
+    /// since it is expensive to calculate
+    pub fn atan2(y: &Self, x: &Self, pi: &Self) -> Self {
+        let mut half_pi = pi.clone();
+        half_pi.awi.ashr_(1).unwrap();
+        if x.is_zero() {
+            if y.is_zero() {
+                Self::zero(y.fp)
+            } else if y.is_negative() {
+                half_pi.neg()
+            } else {
+                half_pi
+            }
+        } else if y.abs().awi.ule(&x.abs().awi).unwrap() {
+            let a = y.div(x).atan();
+            if !x.is_negative() {
+                a
+            } else if y.is_negative() {
+                a.sub(pi)
+            } else {
+                a.add(pi)
+            }
+        } else {
+            let a = x.div(y).atan();
+            if y.is_negative() {
+                half_pi.neg().sub(&a)
+            } else {
+                half_pi.sub(&a)
+            }
+        }
+    }
+
+    /// Returns `atanh(self)` for `|self| < 1`
+    fn atanh(&self) -> Self {
+        // atanh(x) = x + x^3/3 + x^5/5 + ...
+        let x_sqr = self.mul(self);
+        let mut sum = Self::zero(self.fp);
+        let mut pow = self.clone();
+        let mut i = 0;
+        loop {
+            let term = pow.div_int(2 * i + 1);
+            if term.is_zero() {
+                break sum
+            }
+            sum = sum.add(&term);
+            pow = pow.mul(&x_sqr);
+            i += 1;
+        }
+    }
+
+    /// Returns `ln(2)`
+    pub fn ln2(fp: usize) -> Self {
+        // ln(2) = 2*atanh(1/3)
+        let mut res = Self::from_i128(1, fp).div_int(3).atanh();
+        res.awi.shl_(1).unwrap();
+        res
+    }
+
+    /// Natural logarithm, panics on nonpositive inputs
+    pub fn ln(&self) -> Self {
+        assert!(!self.is_negative() && !self.is_zero());
+        let one = Self::from_i128(1, self.fp);
+        let two = Self::from_i128(2, self.fp);
+        // reduce to `[1, 2)` with powers of two
+        let mut x = self.clone();
+        let mut exp = 0i128;
+        while x.awi.ilt(&one.awi).unwrap() {
+            x.awi.shl_(1).unwrap();
+            exp -= 1;
+        }
+        while x.awi.ige(&two.awi).unwrap() {
+            x.awi.ashr_(1).unwrap();
+            exp += 1;
+        }
+        // ln(x) = 2*atanh((x - 1)/(x + 1))
+        let mut res = x.sub(&one).div(&x.add(&one)).atanh();
+        res.awi.shl_(1).unwrap();
+        res.add(&Self::ln2(self.fp).mul(&Self::from_i128(exp, self.fp)))
+    }
+
+    /// Base 2 logarithm, panics on nonpositive inputs
+    pub fn log2(&self) -> Self {
+        self.ln().div(&Self::ln2(self.fp))
+    }
+
+    /// Natural exponential, `self` should not be large enough to overflow
+    pub fn exp(&self) -> Self {
+        // reduce the argument and square the result afterwards
+        let mut x = self.clone();
+        let mut eighth = Self::from_i128(1, self.fp);
+        eighth.awi.ashr_(3).unwrap();
+        let mut squarings = 0;
+        while x.abs().awi.igt(&eighth.awi).unwrap() {
+            x.awi.ashr_(1).unwrap();
+            squarings += 1;
+        }
+        // e^x = 1 + x + x^2/2! + x^3/3! + ...
+        let mut sum = Self::zero(self.fp);
+        let mut term = Self::from_i128(1, self.fp);
+        let mut i = 0;
+        loop {
+            if term.is_zero() {
+                break
+            }
+            sum = sum.add(&term);
+            i += 1;
+            term = term.mul(&x).div_int(i);
+        }
+        for _ in 0..squarings {
+            sum = sum.mul(&sum);
+        }
+        sum
+    }
+}
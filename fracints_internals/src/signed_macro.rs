@@ -1,18 +1,21 @@
 #[macro_export]
 macro_rules! impl_signed {
     (
+        $(#[$meta:meta])*
         $ty:ident,
         $s:expr,
         $iX:ident,
-        $uX:ident,
-        $to_string:ident,
-        $from_str:ident,
+        $to_str_buf:ident,
+        $from_ascii:ident,
+        $str_len:ident,
         $normalized_mul:expr,
-        $normalized_div:expr,
+        $mul_mode:expr,
+        $normalized_div_rem:expr,
         $sqrt_fast:expr,
-        $n:expr,
-        $to_int:ident,
-        $c:expr
+        $exp_neg:expr,
+        $ln_1p:expr,
+        $n:expr
+        $(, $c:expr, $to_ext:expr, $from_ext:expr)?
     ) => {
         // TODO make inner type private, not doing this currently because we need const
         // traits
@@ -20,22 +23,24 @@ macro_rules! impl_signed {
         ///
         /// Note: in the future we want to make the inner type private, but not
         /// currently because we don't have stable const traits.
+        $(#[$meta])*
         #[allow(non_camel_case_types)]
-        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "serde_support", serde(transparent))]
         #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
         pub struct $ty(pub $iX);
 
         impl Fracint for $ty {
             type Int = $iX;
 
-            const BITS: usize = $uX::BITS as usize;
+            const BITS: usize = $iX::BITS as usize;
             const MAX: Self = Self($iX::MAX);
             const MIN: Self = Self($iX::MIN);
-            const NEG_ONE: Self = Self(-$iX::MAX);
+            const NEG_ONE: Self = Self($iX::MAX.wrapping_neg());
             const ONE: Self = Self($iX::MAX);
             const SIGNED: bool = true;
-            const ULP: Self = Self(1);
-            const ZERO: Self = Self(0);
+            const ULP: Self = Self($iX::ONE);
+            const ZERO: Self = Self($iX::ZERO);
 
             fn from_int(x: Self::Int) -> Self {
                 Self(x)
@@ -62,7 +67,7 @@ macro_rules! impl_signed {
             }
 
             fn saturating_inv(self) -> $iX {
-                $iX::MAX.checked_div(self.0).unwrap_or(-1)
+                $iX::MAX.checked_div(self.0).unwrap_or($iX::NEG_ONE)
             }
 
             fn wrapping_add(self, rhs: Self) -> Self {
@@ -116,7 +121,8 @@ macro_rules! impl_signed {
             }
 
             fn wrapping_mul(self, rhs: Self) -> Self {
-                Self($normalized_mul(self.0, rhs.0))
+                let (floor, frac) = $normalized_mul(self.0, rhs.0);
+                Self(Self::round_product(floor, frac, $mul_mode))
             }
 
             fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
@@ -142,6 +148,16 @@ macro_rules! impl_signed {
                 res
             }
 
+            fn mul_with(self, rhs: Self, mode: RoundingMode) -> Self {
+                if self == Self::MIN && rhs == Self::MIN {
+                    return Self::ONE
+                }
+                // the product truncated towards negative infinity and the discarded bits
+                let (floor, frac) = $normalized_mul(self.0, rhs.0);
+                // the product is always less than `ONE`, so this cannot overflow
+                Self(Self::round_product(floor, frac, mode))
+            }
+
             fn saturating_div(self, rhs: Self) -> Self {
                 if rhs == Self::ZERO {
                     self.signum()
@@ -153,24 +169,96 @@ macro_rules! impl_signed {
                         Self::ONE
                     }
                 } else {
-                    Self($normalized_div(self.0, rhs.0))
+                    self.wrapping_div(rhs)
+                }
+            }
+
+            fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+                (
+                    self.wrapping_div(rhs),
+                    self.0.abs_frac() >= rhs.0.abs_frac(),
+                )
+            }
+
+            fn div_rem(self, rhs: Self) -> (Self, Self) {
+                if rhs == Self::ZERO {
+                    panic!("attempt to divide by zero")
                 }
+                let (quo, rem) = $normalized_div_rem(self.0, rhs.0);
+                (Self(quo), Self(rem))
+            }
+
+            fn wrapping_div_int(self, rhs: Self::Int) -> Self {
+                Self(self.0.wrapping_div(rhs))
+            }
+
+            fn overflowing_div_int(self, rhs: Self::Int) -> (Self, bool) {
+                let (quo, overflow) = self.0.overflowing_div(rhs);
+                (Self(quo), overflow)
+            }
+
+            fn checked_div_int(self, rhs: Self::Int) -> Option<Self> {
+                self.0.checked_div(rhs).map(Self)
             }
 
             fn saturating_div_int(self, rhs: Self::Int) -> Self {
-                if rhs == 0 {
+                if rhs == $iX::ZERO {
                     self.signum()
-                } else if (self == Self::MIN) && (rhs == -1) {
+                } else if (self == Self::MIN) && (rhs == $iX::NEG_ONE) {
                     Self::ONE
                 } else {
                     Self(self.0.wrapping_div(rhs))
                 }
             }
 
+            fn div_rem_int(self, rhs: Self::Int) -> (Self, Self) {
+                (
+                    Self(self.0.wrapping_div(rhs)),
+                    Self(self.0.wrapping_rem(rhs)),
+                )
+            }
+
+            fn div_with(self, rhs: Self, mode: RoundingMode) -> Self {
+                if (rhs == Self::ZERO) || (self.0.abs_frac() >= rhs.0.abs_frac()) {
+                    return self.saturating_div(rhs)
+                }
+                // the quotient is truncated towards zero
+                let (quo, rem) = self.div_rem(rhs);
+                let negative = (self < Self::ZERO) != (rhs < Self::ZERO);
+                let inexact = rem != Self::ZERO;
+                let (rem, rhs) = (rem.0.abs_frac(), rhs.0.abs_frac());
+                let away = match mode {
+                    RoundingMode::Floor => negative && inexact,
+                    RoundingMode::Ceil => !negative && inexact,
+                    RoundingMode::TowardZero => false,
+                    // `rem < rhs <= 2^(BITS - 1)` so doubling it cannot overflow
+                    RoundingMode::NearestEven => match rem.shl_or(1, 0).cmp(&rhs) {
+                        Ordering::Less => false,
+                        Ordering::Equal => quo.0.is_odd(),
+                        Ordering::Greater => true,
+                    },
+                };
+                if !away {
+                    quo
+                } else if negative {
+                    quo.wrapping_sub(Self::ULP).max(Self::NEG_ONE)
+                } else {
+                    quo.saturating_add(Self::ULP)
+                }
+            }
+
             fn sqrt_fast(self) -> Self {
                 $sqrt_fast(self)
             }
 
+            fn exp_neg(self) -> Self {
+                $exp_neg(self)
+            }
+
+            fn ln_1p(self) -> Self {
+                $ln_1p(self)
+            }
+
             /// Generates a random fracint from the given entropy.
             /// Note: if `fiN::MIN` is generated, `fiN::ZERO` is returned instead
             ///
@@ -201,10 +289,9 @@ macro_rules! impl_signed {
                 } else if f == -1.0 {
                     return Some(Self::NEG_ONE);
                 }
-                let mut f = F32::from_f32(f);
-                let mut x: FP<inlawi_ty!($n)> = FP::new(true, InlAwi::zero(), $n - 1).unwrap();
-                FP::truncate_(&mut x, &mut f);
-                Some(Self::from_int(x.$to_int()))
+                let mut x = <inlawi_ty!($n)>::zero();
+                f32_to_fixed(&mut x, $n - 1, f);
+                Some(Self::from_int($iX::from_bits(&x)))
             }
 
             fn from_f64(f: f64) -> Option<Self> {
@@ -216,202 +303,90 @@ macro_rules! impl_signed {
                 } else if f == -1.0 {
                     return Some(Self::NEG_ONE);
                 }
-                let mut f = F64::from_f64(f);
-                let mut x: FP<inlawi_ty!($n)> = FP::new(true, InlAwi::zero(), $n - 1).unwrap();
-                FP::truncate_(&mut x, &mut f);
-                Some(Self::from_int(x.$to_int()))
+                let mut x = <inlawi_ty!($n)>::zero();
+                f64_to_fixed(&mut x, $n - 1, f);
+                Some(Self::from_int($iX::from_bits(&x)))
             }
 
             fn to_f32(self) -> f32 {
-                let mut f: FP<inlawi_ty!($n)> =
-                    FP::new(true, InlAwi::from(self.as_int()), $n - 1).unwrap();
-                // the msnb is never greater than 2^0 so is never anywhere near unrepresentable
-                FP::try_to_f32(&mut f).unwrap()
+                let mut x: inlawi_ty!($n) = InlAwi::from(self.as_int());
+                fixed_to_f32(&mut x, true, $n - 1)
             }
 
             fn to_f64(self) -> f64 {
-                let mut f: FP<inlawi_ty!($n)> =
-                    FP::new(true, InlAwi::from(self.as_int()), $n - 1).unwrap();
-                // the msnb is never greater than 2^0 so is never anywhere near unrepresentable
-                FP::try_to_f64(&mut f).unwrap()
+                let mut x: inlawi_ty!($n) = InlAwi::from(self.as_int());
+                fixed_to_f64(&mut x, true, $n - 1)
             }
         }
 
-        impl $ty {
-            // TODO
-            #[doc(hidden)]
-            pub fn __todo() {
-                let _ = (
-                    $c.num_4divtau,
-                    $c.num_4divtau_sqr,
-                    $c.cos_taylor_iters,
-                    $c.sin_taylor_iters,
-                );
-            }
-            /*
-            // TODO I suspect that the polynomial method should work a lot better with cos_sin,
-            // I have seen rational beziers get 0.0004 before, and more segments with
-            // parameterization correction common to each should get full precision.
-
-            /// This is a slower
-            /// Intended to only be called for `-0.5 <= self <= 0.5`. This function has
-            /// overflows outside of this range.
-            fn cos_taudiv4_taylor_base(self) -> $ty {
-                // This is based on the equation
-                // cos((tau/4) * x) =
-                // ( (4/tau)^2 - (x^2)/2 ) / (4/tau)^2
-                // + x^4 / ( (4/tau)^4 * 4! )
-                // - x^6 / ( (4/tau)^6 * 6! )
-                // + x^8 / ( (4/tau)^8 * 8! )
-                // - ...
-
-                // rounding to one and preventing overflow
-                let cutoff = (self.0 as $uX) >> (Self::BITS / 2);
-                if cutoff == 0 || cutoff == ($uX::MAX >> (Self::BITS / 2)) {
-                    return Self::ONE;
-                }
+        impl FracintSigned for $ty {}
 
-                let theta_sqr = self * self;
-                let mut sum = $c.num_4divtau_sqr - (theta_sqr / 2);
-                // overflow cannot happen due to the cutoff around zero
-                sum /= $c.num_4divtau_sqr;
-                // this completes the first term, which has to be computed differently from the
-                // others
-
-                let mut factorial_num: $iX = 2;
-                let mut factorial_mul: $iX = 2;
-                let mut numerator = theta_sqr;
-                let mut num_4divtau_mul = $c.num_4divtau_sqr;
-                for i in 0..$c.cos_taylor_iters {
-                    // update multipliers
-                    num_4divtau_mul *= $c.num_4divtau_sqr;
-                    numerator *= theta_sqr;
-                    factorial_num += 1;
-                    factorial_mul *= factorial_num;
-                    factorial_num += 1;
-                    factorial_mul *= factorial_num;
-                    // The strategy is to first divide the numerator by the factorial, then
-                    // divide by the fiN.
-                    let temp0 = numerator / factorial_mul;
-                    if temp0 == $ty::ZERO {
-                        return sum;
-                    }
-                    let temp1 = temp0 / num_4divtau_mul;
-                    if (i & 0b1) == 0 {
-                        sum += temp1;
-                    } else {
-                        sum -= temp1;
+        impl $ty {
+            /// Rounds the product truncated towards negative infinity to
+            /// `floor` with the discarded bits `frac` according to `mode`
+            #[inline]
+            fn round_product<F: FractionBits>(floor: $iX, frac: F, mode: RoundingMode) -> $iX {
+                let up = match mode {
+                    RoundingMode::Floor => false,
+                    RoundingMode::Ceil => frac != F::ZERO,
+                    RoundingMode::TowardZero => (frac != F::ZERO) && (floor < $iX::ZERO),
+                    RoundingMode::NearestEven => {
+                        (frac > F::HALF) || ((frac == F::HALF) && floor.is_odd())
                     }
-                }
-                sum
+                };
+                if up { floor.wrapping_add($iX::ONE) } else { floor }
             }
+        }
 
-            /// Intended to only be called for `-0.5 <= theta <= 0.5`. This function has
-            /// overflows outside of this range.
-            fn sin_taudiv4_taylor_base(self) -> $ty {
-                // This is based on the equation
-                // sin((tau/4) * x) =
-                // ( x*((4/tau)^2) - (x^3)/6 ) / (4/tau)^3
-                // + x^5 / ( (4/tau)^5 * 5! )
-                // - x^7 / ( (4/tau)^7 * 7! )
-                // + x^9 / ( (4/tau)^9 * 9! )
-                // - ...
-
-                // Early return. The numerator has two `x` in it, so no other checking.
-                if self == $ty::ZERO {
-                    return $ty::ZERO;
-                }
-                let theta_sqr = self * self;
-                let mut numerator = theta_sqr * self;
-                let mut sum = (self * $c.num_4divtau_sqr) - (numerator / 6);
-                let mut num_4divtau_mul = $c.num_4divtau_sqr * $c.num_4divtau;
-                // `sum` cannot be more than `num_4divtau_mul`, assuming the range above
-                sum /= num_4divtau_mul;
-                // this completes the first term, which has to be computed differently from the
-                // others
-
-                let mut factorial_num: $iX = 3;
-                let mut factorial_mul: $iX = 6;
-                for i in 0..$c.sin_taylor_iters {
-                    // update multipliers
-                    num_4divtau_mul *= $c.num_4divtau_sqr;
-                    numerator *= theta_sqr;
-                    factorial_num += 1;
-                    factorial_mul *= factorial_num;
-                    factorial_num += 1;
-                    factorial_mul *= factorial_num;
-
-                    let temp0 = numerator / factorial_mul;
-                    if temp0 == $ty::ZERO {
-                        return sum;
-                    }
-                    let temp1 = temp0 / num_4divtau_mul;
-                    if (i & 0b1) == 0 {
-                        sum += temp1;
-                    } else {
-                        sum -= temp1;
-                    }
-                }
-                sum
-            }
+        $($crate::impl_signed!(@taylor $ty, $c, $to_ext, $from_ext);)?
 
-            /// Calculates `cos((tau/4) * theta)` or `cos((pi/2) * theta)`.
-            /// By having a (tau/4) constant and cleverly rearranging the taylor series,
-            /// this provides a basic way to calculate cosine for fracints.
-            /// Max Error:
-            /// TODO ULPS
-            pub fn cos_taudiv4_taylor(self) -> $ty {
-                if self >= ($ty::MIN / -2) {
-                    -self.wrapping_add($ty::MIN).sin_taudiv4_taylor_base()
-                } else if self >= ($ty::MIN / 2) {
-                    self.cos_taudiv4_taylor_base()
-                } else {
-                    self.wrapping_sub($ty::MIN).sin_taudiv4_taylor_base()
-                }
+        impl $ty {
+            /// Converts to a base 10 string representation in a stack buffer,
+            /// without allocation. This is the same string that the `Display`
+            /// implementation writes. `fiN::ONE` and `fiN::NEG_ONE` are special
+            /// cased to "1.0" and "-1.0" respectively.
+            pub fn to_str_buf(self) -> FracintStrBuf<$str_len> {
+                $to_str_buf(self.0)
+            }
+
+            /// Converts to the shortest base 10 string that parses back to
+            /// `self` in a stack buffer, except that `fiN::MIN` is displayed as
+            /// "-1.0". This is the same as formatting with `{:#}`.
+            pub fn to_str_buf_shortest(self) -> FracintStrBuf<$str_len> {
+                let mut s = FracintStrBuf::new();
+                // the buffer is always large enough
+                fmt::Write::write_fmt(&mut s, format_args!("{self:#}")).unwrap();
+                s
+            }
+
+            /// Converts to the shortest base 10 string that parses back to
+            /// `self`, the same as formatting with `{:#}`.
+            #[cfg(feature = "std")]
+            pub fn to_string_shortest(self) -> String {
+                format!("{self:#}")
+            }
+
+            /// Converts to a string representation in `radix`. Radixes 2, 8, and
+            /// 16 are exact and use the same prefixes as `FromStr`, other radixes
+            /// use enough digits to uniquely represent every value. `fiN::MIN` is
+            /// converted as -1.
+            ///
+            /// # Panics
+            ///
+            /// If `radix` is not in the range `2..=36`
+            #[cfg(feature = "std")]
+            pub fn to_string_radix(self, radix: u32) -> String {
+                let x = self.0;
+                let negative = x < $iX::ZERO;
+                to_str_buf_radix(negative, x == $iX::MIN, x.magnitude(), $ty::BITS - 1, radix)
+                    .to_string()
             }
 
-            /// Calculates `sin((tau/4) * theta)` or `sin((pi/2) * theta)`.
-            /// By having a (tau/4) constant and cleverly rearranging the taylor series,
-            /// this provides a basic way to calculate sine for fracints.
-            /// Max Error:
-            /// TODO ULPS
-            pub fn sin_taudiv4_taylor(self) -> $ty {
-                if self >= ($ty::MIN / -2) {
-                    self.wrapping_add($ty::MIN).cos_taudiv4_taylor_base()
-                } else if self >= ($ty::MIN / 2) {
-                    self.sin_taudiv4_taylor_base()
-                } else {
-                    -self.wrapping_sub($ty::MIN).cos_taudiv4_taylor_base()
-                }
+            /// Conversion from an ASCII byte string representation, using the
+            /// same grammar and rounding as the `FromStr` implementation.
+            pub fn from_ascii(s: &[u8]) -> Result<Self, FracintParseError> {
+                $from_ascii(s).map(Self)
             }
-
-            pub fn cos_sin_pi_taylor(self) -> ($ty, $ty) {
-                // this compares the highest two bits of `self` offset by a eighth of a circle
-                // to determine which combination to use.
-                let o = self.wrapping_add($ty::MIN / -4).0 as $uX;
-                match (
-                    (o & (1 << ($ty::BITS - 2))) != 0,
-                    (o & (1 << ($ty::BITS - 1))) != 0,
-                ) {
-                    (false, false) => {
-                        let t = self * 2;
-                        (t.cos_taudiv4_taylor_base(), t.sin_taudiv4_taylor_base())
-                    }
-                    (true, false) => {
-                        let t = self.wrapping_sub($ty::MIN / -2) * 2;
-                        (-t.sin_taudiv4_taylor_base(), t.cos_taudiv4_taylor_base())
-                    }
-                    (false, true) => {
-                        let t = self.wrapping_add($ty::MIN) * 2;
-                        (-t.cos_taudiv4_taylor_base(), -t.sin_taudiv4_taylor_base())
-                    }
-                    (true, true) => {
-                        let t = self.wrapping_add($ty::MIN / -2) * 2;
-                        (t.sin_taudiv4_taylor_base(), -t.cos_taudiv4_taylor_base())
-                    }
-                }
-            }
-            */
         }
 
         impl fmt::Debug for $ty {
@@ -420,8 +395,7 @@ macro_rules! impl_signed {
             /// `fiN::ONE` and `fiN::NEG_ONE` are special cased to "1.0" and "-1.0"
             /// respectively.
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                // TODO use constant sized buffers
-                write!(f, "{}({})", stringify!($ty), $to_string(self.0))
+                write!(f, "{}({})", stringify!($ty), $to_str_buf(self.0))
             }
         }
 
@@ -429,15 +403,48 @@ macro_rules! impl_signed {
             /// Converts to a base 10 string representation
             ///
             /// `fiN::ONE` and `fiN::NEG_ONE` are special cased to "1.0" and "-1.0"
-            /// respectively.
+            /// respectively. The alternate flag `{:#}` uses the shortest string
+            /// that parses back to the same value. A precision rounds to even to
+            /// that many fraction digits, and the width, fill, alignment, '+',
+            /// and '0' flags are supported the same way as for floats.
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let x = self.0;
+                // `fiN::MIN` is also displayed as -1
+                let one = (x == $iX::MAX) || (x <= $iX::MAX.wrapping_neg());
+                fmt_fraction(f, x < $iX::ZERO, one, x.magnitude(), $ty::BITS - 1)
+            }
+        }
+
+        impl fmt::LowerExp for $ty {
+            /// Converts to a base 10 scientific notation string representation
+            ///
+            /// `fiN::ONE` and `fiN::NEG_ONE` are special cased to "1e0" and "-1e0"
+            /// respectively. Without a precision the shortest digits that parse
+            /// back to the same value are used, otherwise it rounds to even to
+            /// that many digits after the leading digit.
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                // TODO use constant sized buffers
-                write!(f, "{}", $to_string(self.0))
+                let x = self.0;
+                let one = (x == $iX::MAX) || (x <= $iX::MAX.wrapping_neg());
+                fmt_fraction_exp(f, x < $iX::ZERO, one, x.magnitude(), $ty::BITS - 1, false)
             }
         }
 
+        impl fmt::UpperExp for $ty {
+            /// The same as `LowerExp` except with an 'E'
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let x = self.0;
+                let one = (x == $iX::MAX) || (x <= $iX::MAX.wrapping_neg());
+                fmt_fraction_exp(f, x < $iX::ZERO, one, x.magnitude(), $ty::BITS - 1, true)
+            }
+        }
+
+        $crate::impl_signed!(@radix $ty, $iX, fmt::Binary, 2, false);
+        $crate::impl_signed!(@radix $ty, $iX, fmt::Octal, 8, false);
+        $crate::impl_signed!(@radix $ty, $iX, fmt::LowerHex, 16, false);
+        $crate::impl_signed!(@radix $ty, $iX, fmt::UpperHex, 16, true);
+
         impl FromStr for $ty {
-            type Err = FracintSerdeError;
+            type Err = FracintParseError;
 
             // TODO have a function for determining max number of chars with respect to
             // radix
@@ -450,10 +457,13 @@ macro_rules! impl_signed {
             /// be an integer part with digits in the correct radix. If including
             /// the fraction, a '.' followed by one more digits in the correct radix
             /// should be added. Finally, an exponent can be added by 'e' or 'p'
-            /// (except radix 16 which must use 'p') and then a number in the same
-            /// radix is used. The exponent is applied as `* radix^exponent` before
-            /// round-to-even. '_'s can be used throughout the integer, fraction,
-            /// and exponent parts as long as one term is not all underspaces.
+            /// or their uppercase versions (except radix 16 which must use 'p' or
+            /// 'P') and then a number in the same radix is used. The exponent is
+            /// applied as `* radix^exponent` before round-to-even. '_'s can be
+            /// used throughout the integer, fraction, and exponent parts as long as
+            /// one term is not all underspaces. Alternatively, the integer can be
+            /// followed by '/' and an integer denominator, or the number can end
+            /// with '%' to be a percentage.
             ///
             /// `s` can be arbitrarily long but significance changes stops after a
             /// number of chars.
@@ -466,7 +476,7 @@ macro_rules! impl_signed {
             /// See the [FracintParseError] documentation for parsing errors and
             /// examples.
             fn from_str(s: &str) -> Result<Self, Self::Err> {
-                $from_str(s).map(|x| Self(x))
+                $from_ascii(s.as_bytes()).map(Self)
             }
         }
 
@@ -676,4 +686,123 @@ macro_rules! impl_signed {
             }
         }
     };
+    (@taylor $ty:ident, $c:expr, $to_ext:expr, $from_ext:expr) => {
+        impl $ty {
+            // TODO I suspect that the polynomial method should work a lot better with
+            // cos_sin, I have seen rational beziers get 0.0004 before, and more
+            // segments with parameterization correction common to each should get full
+            // precision.
+
+            /// Intended to only be called for `-0.5 <= self <= 0.5`. This function has
+            /// overflows outside of this range.
+            fn cos_taudiv4_taylor_base(self) -> $ty {
+                // the series is evaluated in a larger type and then rounded
+                $from_ext(cos_taudiv4_taylor_base(
+                    $to_ext(self),
+                    $c.num_4divtau_sqr,
+                    $c.cos_taylor_iters,
+                ))
+            }
+
+            /// Intended to only be called for `-0.5 <= self <= 0.5`. This function has
+            /// overflows outside of this range.
+            fn sin_taudiv4_taylor_base(self) -> $ty {
+                $from_ext(sin_taudiv4_taylor_base(
+                    $to_ext(self),
+                    $c.num_4divtau,
+                    $c.num_4divtau_sqr,
+                    $c.sin_taylor_iters,
+                ))
+            }
+
+            /// Calculates `cos((tau/4) * theta)` or `cos((pi/2) * theta)`.
+            /// By having a (tau/4) constant and cleverly rearranging the taylor series,
+            /// this provides a basic way to calculate cosine for fracints.
+            ///
+            /// Max Error: <= 0.5 ULP of the true value, except that numerical 1.0
+            /// is represented by `ONE`.
+            pub fn cos_taudiv4_taylor(self) -> $ty {
+                if self >= ($ty::MIN / -2) {
+                    -self.wrapping_add($ty::MIN).sin_taudiv4_taylor_base()
+                } else if self >= ($ty::MIN / 2) {
+                    self.cos_taudiv4_taylor_base()
+                } else {
+                    self.wrapping_sub($ty::MIN).sin_taudiv4_taylor_base()
+                }
+            }
+
+            /// Calculates `sin((tau/4) * theta)` or `sin((pi/2) * theta)`.
+            /// By having a (tau/4) constant and cleverly rearranging the taylor series,
+            /// this provides a basic way to calculate sine for fracints.
+            ///
+            /// Max Error: <= 0.5 ULP of the true value, except that numerical 1.0
+            /// is represented by `ONE`.
+            pub fn sin_taudiv4_taylor(self) -> $ty {
+                if self >= ($ty::MIN / -2) {
+                    self.wrapping_add($ty::MIN).cos_taudiv4_taylor_base()
+                } else if self >= ($ty::MIN / 2) {
+                    self.sin_taudiv4_taylor_base()
+                } else {
+                    -self.wrapping_sub($ty::MIN).cos_taudiv4_taylor_base()
+                }
+            }
+
+            /// Calculates `(cos(pi * theta), sin(pi * theta))`, where `theta` is
+            /// in half turns. `fiN::MIN` is the same angle as `fiN::ONE + fiN::ULP`.
+            ///
+            /// Max Error: <= 0.5 ULP of the true value, except that numerical 1.0
+            /// is represented by `ONE`.
+            pub fn cos_sin_pi_taylor(self) -> ($ty, $ty) {
+                // this compares the highest two bits of `self` offset by a eighth of a circle
+                // to determine which combination to use.
+                let o = self.wrapping_add($ty::MIN / -4).0;
+                match ((o & (1 << ($ty::BITS - 2))) != 0, o < 0) {
+                    (false, false) => {
+                        let t = self * 2;
+                        (t.cos_taudiv4_taylor_base(), t.sin_taudiv4_taylor_base())
+                    }
+                    (true, false) => {
+                        let t = self.wrapping_sub($ty::MIN / -2) * 2;
+                        (-t.sin_taudiv4_taylor_base(), t.cos_taudiv4_taylor_base())
+                    }
+                    (false, true) => {
+                        let t = self.wrapping_add($ty::MIN) * 2;
+                        (-t.cos_taudiv4_taylor_base(), -t.sin_taudiv4_taylor_base())
+                    }
+                    (true, true) => {
+                        let t = self.wrapping_add($ty::MIN / -2) * 2;
+                        (t.sin_taudiv4_taylor_base(), -t.cos_taudiv4_taylor_base())
+                    }
+                }
+            }
+
+            /// The `Fracint::exp_neg` implementation
+            fn exp_neg_taylor(self) -> $ty {
+                if self <= $ty::ZERO {
+                    return $ty::ONE
+                }
+                $from_ext(exp_neg_taylor_base($to_ext(self), $c.exp_taylor_iters))
+            }
+
+            /// The `Fracint::ln_1p` implementation
+            fn ln_1p_taylor(self) -> $ty {
+                let half = ln_1p_div2_taylor_base($to_ext(self), $c.ln_taylor_iters);
+                $from_ext(half.saturating_add(half))
+            }
+        }
+    };
+    (@radix $ty:ident, $iX:ident, $trait:path, $radix:expr, $upper:expr) => {
+        impl $trait for $ty {
+            /// Converts to an exact string representation in the radix with the
+            /// prefix that `FromStr` uses. `fiN::MIN` is formatted as -1, and
+            /// a precision rounds to even to that many fraction digits.
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                const FP: usize = $ty::BITS - 1;
+                let x = self.0;
+                let digits = const { unique_fraction_digits_radix(FP, $radix) };
+                let (negative, min) = (x < $iX::ZERO, x == $iX::MIN);
+                fmt_fraction_radix(f, negative, min, x.magnitude(), $radix, $upper, digits)
+            }
+        }
+    };
 }
@@ -1,6 +1,6 @@
 #[macro_export]
 macro_rules! impl_signed_double {
-    ($ty:ident, $tyD:ident, $iX:ident, $uX:ident, $iD:ident, $uD:ident) => {
+    ($ty:ident, $tyD:ident, $iX:ident, $iD:ident) => {
         impl FracintDouble for $ty {
             type Double = $tyD;
 
@@ -21,11 +21,14 @@ macro_rules! impl_signed_double {
             type Half = $ty;
 
             fn split(self) -> ($ty, $ty) {
-                ($ty(self.0 as $iX), $ty((self.0 >> $ty::BITS) as $iX))
+                (
+                    $ty($iX::wrapping_from(self.0)),
+                    $ty($iX::wrapping_from(self.0 >> $ty::BITS)),
+                )
             }
 
             fn truncate(self) -> $ty {
-                $ty((self.0 >> $ty::BITS) as $iX)
+                $ty($iX::wrapping_from(self.0 >> $ty::BITS))
             }
         }
 
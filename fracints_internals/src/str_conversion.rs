@@ -1,26 +1,80 @@
-use std::num::NonZeroUsize;
+use core::{
+    fmt::{self, Write},
+    ops::Deref,
+    str,
+};
 
-use awint::{Awi, FP, InlAwi};
 use thiserror::Error;
 
+use crate::i256;
+
+/// The kind of error that happened when parsing a fracint, see
+/// [FracintParseError] for the grammar. This is also used by itself where there
+/// is no position information.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum FracintSerdeError {
+    #[error("Radix is outside of the range `2..=36`")]
+    RadixOutOfRange,
+    #[error("Input was an empty string")]
+    Empty,
+    #[error("The input was not Ascii")]
+    NotAscii,
+    #[error("The input did not have an integer part")]
+    EmptyInteger,
+    #[error("The integer part has a char not in the radix")]
+    InvalidCharInInteger,
+    #[error(
+        "The input does not have a fraction, there should always be a '.' and digits following it"
+    )]
+    EmptyFraction,
+    #[error("There was an invalid char in the fraction")]
+    InvalidCharInFraction,
+    #[error("There was a char for the exponent but the value was empty")]
+    EmptyExponent,
+    #[error("The exponent was empty, or there was a char in the exponent that was not valid")]
+    InvalidCharInExponent,
+    #[error("The numeric value was not in range")]
+    Overflow,
+    #[error("miscellanious error")]
+    Other,
+}
+
+/// Which bound of the representable range a parsed value exceeded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FracintBound {
+    /// The value was less than -1 for signed fracints, or was negative for
+    /// unsigned fracints
+    Lower,
+    /// The value was greater than 1
+    Upper,
+}
+
 // TODO these docs could probably be at a module level and be more comprehensive
 
-/// The error enum used to specify what parsing error happened when parsing a
-/// fracint.
+/// The error returned when parsing a fracint, with the kind of error, the
+/// byte offset of where it happened, and for `Overflow` which bound was
+/// exceeded.
 ///
 /// The input consists of an integer part, optional fraction part, and optional
 /// exponent part. It can have a prefixed '-' to be negative, then a radix other
 /// than 10 can be specified that will apply to the following parts, then the
 /// integer part is specified. The fraction part begins with '.', and the
-/// exponent can be started at the end with 'e' or 'p', and can include a '-' to
-/// be negative. A single rigorous round-to-even is applied after the exponent
-/// is applied, and then bounds are checked. The value "1.0" is special cased to
-/// `fiN::ONE` and "-1" is special cased to `fiN::NEG_ONE`.
+/// exponent can be started at the end with 'e' or 'p' (or 'E' or 'P'), and can
+/// include a '-' to be negative. A single rigorous round-to-even is applied
+/// after the exponent is applied, and then bounds are checked. The value "1.0"
+/// is special cased to `fiN::ONE` and "-1" is special cased to `fiN::NEG_ONE`.
+///
+/// Instead of a fraction and exponent, the integer part can be followed by '/'
+/// and an integer denominator with its own optional radix prefix, such as
+/// "-1/3" or "0x10/0b1000_0000". The numerator and denominator must fit in a
+/// `u128` and the ratio is rounded to even exactly, a zero denominator is an
+/// `Overflow`. A radix 10 number without a denominator can also end with '%'
+/// to be divided by 100, such as "12.5%".
 ///
 /// ```
 /// use core::str::FromStr;
 ///
-/// use fracints::{Fracint, fi8, fi64, fi128};
+/// use fracints::{Fracint, FracintBound, FracintSerdeError, fi8, fi64, fi128};
 ///
 /// assert_eq!(fi64!(1), fi64::ONE);
 /// assert_eq!(fi64!(1.000_000), fi64::ONE);
@@ -52,51 +106,770 @@ use thiserror::Error;
 /// assert_eq!(fi8!(0.01171874), fi8(1));
 /// assert_eq!(fi8!(0.01171875), fi8(2));
 ///
-/// // Other radixes, note that certain literals currently fail to parse in Rust,
-/// // so the macro unfortunately can't be used.
+/// // Other radixes, note that certain literals fail to tokenize in Rust, so
+/// // the macros also accept string literals
 /// assert_eq!(fi8::from_str("0b0.1010101").unwrap(), fi8!(0.664));
+/// assert_eq!(fi8!("0b0.1010101"), fi8!(0.664));
+/// assert_eq!(fi8!(0x0.aa), fi8!(0.664));
 /// assert_eq!(
 ///     fi64::from_str("-0xbeef.123456_p-5").unwrap(),
 ///     fi64!(-0.046614714728434592)
 /// );
+/// assert_eq!(fi64!("-0xbeef.123456_P-5"), fi64!(-0.046614714728434592));
+///
+/// // ratios and percentages
+/// assert_eq!(fi64::from_str("1/3").unwrap(), fi64!(0.333333333333333333333));
+/// assert_eq!(fi8::from_str("-3/4").unwrap(), fi8!(-0.75));
+/// assert_eq!(fi8::from_str("12.5%").unwrap(), fi8!(0.125));
+/// assert_eq!(fi8!(50%), fi8!(0.5));
+///
+/// // errors
+/// let err = fi8::from_str("0.12a4").unwrap_err();
+/// assert_eq!(err.kind(), FracintSerdeError::InvalidCharInFraction);
+/// assert_eq!(err.position(), 4);
+/// let err = fi8::from_str("-1.5").unwrap_err();
+/// assert_eq!(err.kind(), FracintSerdeError::Overflow);
+/// assert_eq!(err.bound(), Some(FracintBound::Lower));
+/// assert_eq!(
+///     err.to_string(),
+///     "The numeric value was not in range, the lower bound was exceeded"
+/// );
 /// ```
-#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
-pub enum FracintSerdeError {
-    #[error("Radix is outside of the range `2..=36`")]
-    RadixOutOfRange,
-    #[error("Input was an empty string")]
-    Empty,
-    #[error("The input was not Ascii")]
-    NotAscii,
-    #[error("The input did not have an integer part")]
-    EmptyInteger,
-    #[error("The integer part has a char not in the radix")]
-    InvalidCharInInteger,
-    #[error(
-        "The input does not have a fraction, there should always be a '.' and digits following it"
-    )]
-    EmptyFraction,
-    #[error("There was an invalid char in the fraction")]
-    InvalidCharInFraction,
-    #[error("There was a char for the exponent but the value was empty")]
-    EmptyExponent,
-    #[error("The exponent was empty, or there was a char in the exponent that was not valid")]
-    InvalidCharInExponent,
-    #[error("The numeric value was not in range")]
-    Overflow,
-    #[error("miscellanious error")]
-    Other,
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FracintParseError {
+    kind: FracintSerdeError,
+    position: usize,
+    negative: bool,
+}
+
+impl FracintParseError {
+    /// Creates a parse error of `kind` at the byte offset `position`, with
+    /// `negative` being the sign of the parsed value
+    pub const fn new(kind: FracintSerdeError, position: usize, negative: bool) -> Self {
+        Self {
+            kind,
+            position,
+            negative,
+        }
+    }
+
+    /// The kind of error
+    pub const fn kind(&self) -> FracintSerdeError {
+        self.kind
+    }
+
+    /// The byte offset of the offending char. For `Empty*` errors this is
+    /// where the missing part should start, and for `Overflow` this is the
+    /// start of the digits, or of the '-' if only the sign is out of range
+    /// for an unsigned fracint.
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// For `Overflow` errors, returns if the value was negative
+    pub const fn is_negative(&self) -> Option<bool> {
+        match self.kind {
+            FracintSerdeError::Overflow => Some(self.negative),
+            _ => None,
+        }
+    }
+
+    /// For `Overflow` errors, returns which bound was exceeded
+    pub const fn bound(&self) -> Option<FracintBound> {
+        match self.is_negative() {
+            Some(true) => Some(FracintBound::Lower),
+            Some(false) => Some(FracintBound::Upper),
+            None => None,
+        }
+    }
+}
+
+impl fmt::Display for FracintParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.bound() {
+            Some(FracintBound::Lower) => write!(f, "{}, the lower bound was exceeded", self.kind),
+            Some(FracintBound::Upper) => write!(f, "{}, the upper bound was exceeded", self.kind),
+            None => write!(f, "{} at byte {}", self.kind, self.position),
+        }
+    }
+}
+
+impl core::error::Error for FracintParseError {}
+
+impl From<FracintParseError> for FracintSerdeError {
+    fn from(e: FracintParseError) -> Self {
+        e.kind
+    }
+}
+
+impl PartialEq<FracintSerdeError> for FracintParseError {
+    fn eq(&self, other: &FracintSerdeError) -> bool {
+        self.kind == *other
+    }
+}
+
+/// A fixed size stack buffer holding the base 10 string representation of a
+/// fracint, so that fracints can be formatted without allocation. This
+/// dereferences to `str`.
+#[derive(Clone, Copy)]
+pub struct FracintStrBuf<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> FracintStrBuf<N> {
+    /// Returns an empty buffer
+    pub const fn new() -> Self {
+        Self {
+            buf: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Returns the string
+    pub fn as_str(&self) -> &str {
+        // only ASCII is ever written to the buffer
+        str::from_utf8(&self.buf[..self.len]).unwrap()
+    }
 }
 
-// TODO do this in a more optimized way without `awint`
+impl<const N: usize> Default for FracintStrBuf<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Appends to the buffer, returning an error if it would overflow
+impl<const N: usize> fmt::Write for FracintStrBuf<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > N {
+            return Err(fmt::Error)
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl<const N: usize> Deref for FracintStrBuf<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for FracintStrBuf<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> fmt::Display for FracintStrBuf<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<const N: usize> fmt::Debug for FracintStrBuf<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+/// Multiplies `x` by `radix`, returning the low 128 bits and the overflowing
+/// high digit
+const fn mul_digit(x: u128, radix: u8) -> (u128, u8) {
+    let lo = (x as u64 as u128) * (radix as u128);
+    let hi = (x >> 64) * (radix as u128) + (lo >> 64);
+    ((hi << 64) | (lo as u64 as u128), (hi >> 64) as u8)
+}
+
+/// Multiplies the 256 bit `(hi, lo)` by `radix`, returning the low 256 bits
+/// and the overflowing high digit
+const fn mul_digit_wide(x: (u128, u128), radix: u8) -> ((u128, u128), u8) {
+    let (lo, carry) = mul_digit(x.1, radix);
+    let (hi, digit) = mul_digit(x.0, radix);
+    let (hi, overflow) = hi.overflowing_add(carry as u128);
+    ((hi, lo), digit + (overflow as u8))
+}
+
+/// The unsigned integers that fractions are converted with. For parsing the
+/// value is `frac / 2^fp`, and for formatting the fraction is shifted to the
+/// top so that the value is `frac / 2^BITS`. `u128` is used for up to 128
+/// fraction bits and the `(high, low)` halves of a 256 bit integer are used
+/// for up to 256 fraction bits.
+pub trait FractionBits: Copy + Ord {
+    /// The number of bits
+    const BITS: usize;
+    /// Zero
+    const ZERO: Self;
+    /// Only the most significant bit set, which is one half when formatting
+    const HALF: Self;
+
+    /// Zero extends `x`
+    fn from_u128(x: u128) -> Self;
+    /// Returns `2^fp - 1`
+    fn mask(fp: usize) -> Self;
+    /// Returns if the least significant bit is set
+    fn is_odd(self) -> bool;
+    /// Adds one, which should not overflow
+    fn add_one(self) -> Self;
+    /// Sets bit `i`
+    fn set_bit(self, i: usize) -> Self;
+    /// Returns `(self << s) | x` for `x < 2^s` and `s < 128`
+    fn shl_or(self, s: usize, x: u128) -> Self;
+    /// Logical right shift by `s < BITS`
+    fn shr(self, s: usize) -> Self;
+    /// Multiplies by `radix`, returning the low bits and the overflowing high
+    /// digit
+    fn mul_digit(self, radix: u8) -> (Self, u8);
+}
+
+impl FractionBits for u128 {
+    const BITS: usize = 128;
+    const HALF: Self = 1 << 127;
+    const ZERO: Self = 0;
+
+    fn from_u128(x: u128) -> Self {
+        x
+    }
+
+    fn mask(fp: usize) -> Self {
+        u128::MAX >> (128 - fp)
+    }
+
+    fn is_odd(self) -> bool {
+        (self & 1) != 0
+    }
+
+    fn add_one(self) -> Self {
+        self + 1
+    }
+
+    fn set_bit(self, i: usize) -> Self {
+        self | (1 << i)
+    }
+
+    fn shl_or(self, s: usize, x: u128) -> Self {
+        (self << s) | x
+    }
+
+    fn shr(self, s: usize) -> Self {
+        self >> s
+    }
+
+    fn mul_digit(self, radix: u8) -> (Self, u8) {
+        mul_digit(self, radix)
+    }
+}
+
+impl FractionBits for (u128, u128) {
+    const BITS: usize = 256;
+    const HALF: Self = (1 << 127, 0);
+    const ZERO: Self = (0, 0);
+
+    fn from_u128(x: u128) -> Self {
+        (0, x)
+    }
+
+    fn mask(fp: usize) -> Self {
+        if fp <= 128 {
+            (0, u128::mask(fp))
+        } else {
+            (u128::mask(fp - 128), u128::MAX)
+        }
+    }
+
+    fn is_odd(self) -> bool {
+        self.1.is_odd()
+    }
+
+    fn add_one(self) -> Self {
+        let (lo, carry) = self.1.overflowing_add(1);
+        (self.0 + (carry as u128), lo)
+    }
+
+    fn set_bit(self, i: usize) -> Self {
+        if i < 128 {
+            (self.0, self.1.set_bit(i))
+        } else {
+            (self.0.set_bit(i - 128), self.1)
+        }
+    }
+
+    fn shl_or(self, s: usize, x: u128) -> Self {
+        if s == 0 {
+            self
+        } else {
+            ((self.0 << s) | (self.1 >> (128 - s)), (self.1 << s) | x)
+        }
+    }
+
+    fn shr(self, s: usize) -> Self {
+        if s == 0 {
+            self
+        } else if s < 128 {
+            (self.0 >> s, (self.1 >> s) | (self.0 << (128 - s)))
+        } else {
+            (0, self.0 >> (s - 128))
+        }
+    }
+
+    fn mul_digit(self, radix: u8) -> (Self, u8) {
+        mul_digit_wide(self, radix)
+    }
+}
+
+/// Returns the number of base 10 fraction digits needed to uniquely represent
+/// every fixed point value with `fp` fraction bits (`1 <= fp <= 256`). This
+/// matches `awint`'s `FPType::unique_min_fraction_digits`.
+pub const fn unique_fraction_digits(fp: usize) -> usize {
+    unique_fraction_digits_radix(fp, 10)
+}
+
+/// Returns the number of fraction digits in `radix` needed to uniquely
+/// represent every fixed point value with `fp` fraction bits. For radixes that
+/// are powers of two, this is the number of digits needed to represent every
+/// value exactly.
+pub const fn unique_fraction_digits_radix(fp: usize, radix: u8) -> usize {
+    // the ULP as a 256 bit fraction
+    let mut x = if fp <= 128 {
+        (1u128 << (128 - fp), 0)
+    } else {
+        (0, 1u128 << (256 - fp))
+    };
+    let mut digits = 0;
+    loop {
+        digits += 1;
+        let (lo, hi) = mul_digit_wide(x, radix);
+        if hi != 0 {
+            break
+        }
+        x = lo;
+    }
+    digits
+}
+
+fn digit_char(d: u8, upper: bool) -> u8 {
+    if d < 10 {
+        b'0' + d
+    } else if upper {
+        b'A' + (d - 10)
+    } else {
+        b'a' + (d - 10)
+    }
+}
+
+/// Writes the leading `radix` digits of `frac / 2^BITS` into `buf`, with exact
+/// ties rounding to odd if `ties_to_odd` is set and to even otherwise.
+/// Returns if the round up carried into the units place.
+fn fraction_digits<F: FractionBits>(
+    buf: &mut [u8],
+    mut frac: F,
+    radix: u8,
+    upper: bool,
+    ties_to_odd: bool,
+) -> bool {
+    let mut last = 0;
+    for c in buf.iter_mut() {
+        let (lo, hi) = frac.mul_digit(radix);
+        *c = digit_char(hi, upper);
+        last = hi;
+        frac = lo;
+    }
+    if (frac > F::HALF) || ((frac == F::HALF) && (((last & 1) != 0) != ties_to_odd)) {
+        let max = digit_char(radix - 1, upper);
+        for c in buf.iter_mut().rev() {
+            if *c == max {
+                *c = b'0';
+            } else {
+                *c = digit_char(digit_value(*c) + 1, upper);
+                return false
+            }
+        }
+        return true
+    }
+    false
+}
+
+/// Writes "0." or "1." followed by the `digits` fraction digits of
+/// `frac / 2^BITS` (or of exactly one if `one` is set) to the start of `buf`,
+/// optionally trimming trailing zeros. Ties round the same as
+/// `fraction_digits`. Returns the number of bytes written.
+#[allow(clippy::too_many_arguments)]
+fn write_body<F: FractionBits>(
+    buf: &mut [u8],
+    one: bool,
+    frac: F,
+    radix: u8,
+    upper: bool,
+    digits: usize,
+    trim: bool,
+    ties_to_odd: bool,
+) -> usize {
+    let end = 2 + digits;
+    let carry = if one {
+        buf[2..end].fill(b'0');
+        true
+    } else {
+        fraction_digits(&mut buf[2..end], frac, radix, upper, ties_to_odd)
+    };
+    buf[0] = if carry { b'1' } else { b'0' };
+    buf[1] = b'.';
+    let mut len = end;
+    if trim {
+        while (len > 3) && (buf[len - 1] == b'0') {
+            len -= 1;
+        }
+    }
+    len
+}
 
-/// Takes a string and the bitwidth of the result type
-fn common_from_str(
-    s: &str,
-    bw: isize,
-) -> Result<Result<(bool, Awi), awint::SerdeError>, FracintSerdeError> {
+/// Writes "0." followed by the `digits` decimal fraction digits of
+/// `frac / 2^BITS` rounded to odd to the start of `buf`, trimming trailing
+/// zeros. Returns the number of bytes written.
+fn write_fraction<F: FractionBits>(buf: &mut [u8], frac: F, digits: usize) -> usize {
+    let len = write_body(buf, false, frac, 10, false, digits, true, true);
+    // this never carries into the integer part because `ONE` is special cased
+    debug_assert_eq!(buf[0], b'0');
+    len
+}
+
+/// Returns the fewest fraction digits of `frac / 2^BITS` that parse back to
+/// the same value with `fp` fraction bits
+fn shortest_fraction_digits<F: FractionBits>(frac: F, fp: usize) -> usize {
+    let expected = Ok((false, Some(frac.shr(F::BITS - fp))));
+    // enough for the unique digits of 256 fraction bits
+    let mut buf = [0u8; 2 + 78];
+    buf[0] = b'0';
+    buf[1] = b'.';
+    // The nearest decimal with a given number of digits is always the best
+    // candidate, and if it parses back correctly then so does the nearest
+    // decimal with more digits, so we can binary search. The unique digits
+    // always work.
+    let mut lo = 1;
+    let mut hi = unique_fraction_digits(fp);
+    while lo < hi {
+        let mid = lo + ((hi - lo) / 2);
+        let carry = fraction_digits(&mut buf[2..(2 + mid)], frac, 10, false, false);
+        if !carry && (common_from_ascii(&buf[..(2 + mid)], fp) == expected) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    lo
+}
+
+/// Returns the prefix that `FromStr` uses for `radix`
+fn radix_prefix(radix: u8) -> &'static str {
+    match radix {
+        2 => "0b",
+        8 => "0o",
+        16 => "0x",
+        _ => "",
+    }
+}
+
+/// Writes the sign, `prefix`, `body`, `zeros` zeros, and `suffix` while
+/// handling the width, fill, alignment, '+', and '0' flags of `f`
+fn pad(
+    f: &mut fmt::Formatter,
+    negative: bool,
+    prefix: &str,
+    body: &[u8],
+    zeros: usize,
+    suffix: &str,
+) -> fmt::Result {
+    let sign = if negative {
+        "-"
+    } else if f.sign_plus() {
+        "+"
+    } else {
+        ""
+    };
+    let len = sign.len() + prefix.len() + body.len() + zeros + suffix.len();
+    let padding = f.width().unwrap_or(0).saturating_sub(len);
+    let (pre, post) = if f.sign_aware_zero_pad() {
+        (0, 0)
+    } else {
+        match f.align() {
+            Some(fmt::Alignment::Left) => (0, padding),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - (padding / 2)),
+            _ => (padding, 0),
+        }
+    };
+    for _ in 0..pre {
+        f.write_char(f.fill())?;
+    }
+    f.write_str(sign)?;
+    f.write_str(prefix)?;
+    if f.sign_aware_zero_pad() {
+        for _ in 0..padding {
+            f.write_char('0')?;
+        }
+    }
+    // only ASCII is ever written to the buffers
+    f.write_str(str::from_utf8(body).unwrap())?;
+    for _ in 0..zeros {
+        f.write_char('0')?;
+    }
+    f.write_str(suffix)?;
+    for _ in 0..post {
+        f.write_char(f.fill())?;
+    }
+    Ok(())
+}
+
+/// Formats a fracint with a magnitude of `frac / 2^BITS` (or exactly one if
+/// `one` is set) and `fp` fraction bits. Without a precision this writes the
+/// same string as the `*_to_str_buf` functions (with exact ties rounding to
+/// odd), or the shortest string that parses back to the same value with the
+/// alternate flag. A precision rounds to even to that many fraction digits.
+/// The width, fill, alignment, '+', and '0' flags are also supported.
+pub fn fmt_fraction<F: FractionBits>(
+    f: &mut fmt::Formatter,
+    negative: bool,
+    one: bool,
+    frac: F,
+    fp: usize,
+) -> fmt::Result {
+    let digits = if one || (frac == F::ZERO) {
+        1
+    } else if f.alternate() {
+        shortest_fraction_digits(frac, fp)
+    } else {
+        unique_fraction_digits(fp)
+    };
+    // the shortest digits are found with round to even
+    let ties_to_odd = !f.alternate();
+    fmt_body(f, negative, one, frac, 10, false, digits, ties_to_odd)
+}
+
+/// Formats a fracint with a magnitude of `frac / 2^BITS` (or exactly one if
+/// `one` is set) in `radix` with the prefix that `FromStr` uses. Without a
+/// precision this uses up to `digits` fraction digits and trims trailing
+/// zeros. A precision rounds to even to that many fraction digits. The width,
+/// fill, alignment, '+', and '0' flags are also supported.
+pub fn fmt_fraction_radix<F: FractionBits>(
+    f: &mut fmt::Formatter,
+    negative: bool,
+    one: bool,
+    frac: F,
+    radix: u8,
+    upper: bool,
+    digits: usize,
+) -> fmt::Result {
+    fmt_body(f, negative, one, frac, radix, upper, digits, false)
+}
+
+/// `fmt_fraction_radix` with the tie rounding without a precision selectable
+#[allow(clippy::too_many_arguments)]
+fn fmt_body<F: FractionBits>(
+    f: &mut fmt::Formatter,
+    negative: bool,
+    one: bool,
+    frac: F,
+    radix: u8,
+    upper: bool,
+    digits: usize,
+    ties_to_odd: bool,
+) -> fmt::Result {
+    // an integer digit, '.', and all digits of an exact 256 bit fraction
+    let mut buf = [0u8; 2 + 256];
+    let prefix = radix_prefix(radix);
+    match f.precision() {
+        None => {
+            let len = write_body(&mut buf, one, frac, radix, upper, digits, true, ties_to_odd);
+            pad(f, negative, prefix, &buf[..len], 0, "")
+        }
+        Some(precision) => {
+            // digits past `BITS` are always zero
+            let digits = precision.min(F::BITS);
+            let len = write_body(&mut buf, one, frac, radix, upper, digits, false, false);
+            // no '.' for zero precision
+            let len = if precision == 0 { 1 } else { len };
+            pad(f, negative, prefix, &buf[..len], precision - digits, "")
+        }
+    }
+}
+
+/// Formats a fracint with a magnitude of `frac / 2^BITS` (or exactly one if
+/// `one` is set) and `fp` fraction bits in decimal scientific notation, using
+/// the shortest digits that parse back to the same value unless there is a
+/// precision. The width, fill, alignment, '+', and '0' flags are also
+/// supported.
+pub fn fmt_fraction_exp<F: FractionBits>(
+    f: &mut fmt::Formatter,
+    negative: bool,
+    one: bool,
+    frac: F,
+    fp: usize,
+    upper: bool,
+) -> fmt::Result {
+    let e = if upper { 'E' } else { 'e' };
+    // a leading digit, '.', and the significant digits
+    let mut buf = [0u8; 2 + 256];
+    if one || (frac == F::ZERO) {
+        buf[0] = if one { b'1' } else { b'0' };
+        let precision = f.precision().unwrap_or(0);
+        let (len, zeros) = if precision == 0 {
+            (1, 0)
+        } else {
+            buf[1] = b'.';
+            (2, precision)
+        };
+        let suffix = if upper { "E0" } else { "e0" };
+        return pad(f, negative, "", &buf[..len], zeros, suffix)
+    }
+    // the digits of the fraction, which are exact with `BITS` digits
+    let mut digits = [0u8; 256];
+    let num_digits = if f.precision().is_some() {
+        F::BITS
+    } else {
+        shortest_fraction_digits(frac, fp)
+    };
+    let carry = fraction_digits(&mut digits[..num_digits], frac, 10, false, false);
+    debug_assert!(!carry);
+    let first = digits.iter().position(|c| *c != b'0').unwrap();
+    let mut exp = -(first as isize) - 1;
+    let sig = &digits[first..num_digits];
+    let (len, zeros) = if let Some(precision) = f.precision() {
+        // round to even to `precision + 1` significant digits
+        let kept = (precision + 1).min(sig.len());
+        buf[..kept].copy_from_slice(&sig[..kept]);
+        let rest = &sig[kept..];
+        let round_up = match rest.first() {
+            Some(b'5') => {
+                rest[1..].iter().any(|c| *c != b'0') || ((digit_value(buf[kept - 1]) & 1) != 0)
+            }
+            Some(c) => *c > b'5',
+            None => false,
+        };
+        if round_up {
+            let mut carry = true;
+            for c in buf[..kept].iter_mut().rev() {
+                if *c == b'9' {
+                    *c = b'0';
+                } else {
+                    *c += 1;
+                    carry = false;
+                    break
+                }
+            }
+            if carry {
+                // all nines rounded up to a power of ten
+                buf[0] = b'1';
+                exp += 1;
+            }
+        }
+        // insert the '.'
+        buf.copy_within(1..kept, 2);
+        buf[1] = b'.';
+        let len = if precision == 0 { 1 } else { kept + 1 };
+        (len, (precision + 1) - kept)
+    } else {
+        let mut kept = sig.len();
+        while (kept > 1) && (sig[kept - 1] == b'0') {
+            kept -= 1;
+        }
+        buf[0] = sig[0];
+        if kept == 1 {
+            (1, 0)
+        } else {
+            buf[1] = b'.';
+            buf[2..(kept + 1)].copy_from_slice(&sig[1..kept]);
+            (kept + 1, 0)
+        }
+    };
+    // the exponent is at most 4 chars
+    let mut suffix = FracintStrBuf::<8>::new();
+    write!(suffix, "{e}{exp}")?;
+    pad(f, negative, "", &buf[..len], zeros, &suffix)
+}
+
+/// The length of the [FracintStrBuf] returned by [to_str_buf_radix]
+pub const RADIX_STR_LEN: usize = 5 + 256;
+
+/// Converts a fracint with a magnitude of `frac / 2^BITS` (or exactly one if
+/// `one` is set) and `fp` fraction bits to a string in `radix`. Radixes 2, 8,
+/// and 16 use the prefixes that `FromStr` uses and are exact, other radixes
+/// round to even with enough digits to uniquely represent every value.
+///
+/// # Panics
+///
+/// If `radix` is not in the range `2..=36`
+pub fn to_str_buf_radix<F: FractionBits>(
+    negative: bool,
+    one: bool,
+    frac: F,
+    fp: usize,
+    radix: u32,
+) -> FracintStrBuf<RADIX_STR_LEN> {
+    assert!(
+        (2..=36).contains(&radix),
+        "radix is outside of the range `2..=36`"
+    );
+    let radix = radix as u8;
+    let mut res = FracintStrBuf::new();
+    if negative {
+        res.buf[0] = b'-';
+        res.len = 1;
+    }
+    let prefix = radix_prefix(radix).as_bytes();
+    res.buf[res.len..(res.len + prefix.len())].copy_from_slice(prefix);
+    res.len += prefix.len();
+    let digits = unique_fraction_digits_radix(fp, radix);
+    res.len += write_body(
+        &mut res.buf[res.len..],
+        one,
+        frac,
+        radix,
+        false,
+        digits,
+        true,
+        false,
+    );
+    res
+}
+
+/// The maximum number of fraction digits that can affect parsing, values in
+/// the range `[0, 2)` with up to 256 fraction bits can be exactly determined
+/// with 257 decimal fraction digits and the rest only matter for whether the
+/// value is exactly halfway. Values with more leading zeros than this always
+/// round to zero in any radix.
+const MAX_FRACTION_DIGITS: usize = 258;
+
+fn digit_value(c: u8) -> u8 {
+    match c {
+        b'0'..=b'9' => c - b'0',
+        b'a'..=b'f' => c - b'a' + 10,
+        b'A'..=b'F' => c - b'A' + 10,
+        _ => unreachable!(),
+    }
+}
+
+/// The parts of a string following the grammar
+struct Parts<'a> {
+    sign: bool,
+    /// The byte offset of the start of the integer part
+    start: usize,
+    radix: u8,
+    integer: &'a [u8],
+    fraction: &'a [u8],
+    exp: isize,
+    /// The radix and digits of the denominator if there is one
+    denominator: Option<(u8, &'a [u8])>,
+}
+
+/// Checks the grammar and splits the string into its parts
+fn scan(s: &[u8]) -> Result<Parts<'_>, FracintParseError> {
     use FracintSerdeError::*;
 
+    let err = |kind, position, negative| Err(FracintParseError::new(kind, position, negative));
+
     let sign;
     let integer;
     let mut fraction = None;
@@ -131,16 +904,31 @@ fn common_from_str(
         }
     };
 
-    let s = s.as_bytes();
+    let is_exponent_char = |c: u8| matches!(c, b'e' | b'E' | b'p' | b'P');
+
+    // returns the radix and the length of its prefix
+    let radix_prefix = |s: &[u8]| match s {
+        [b'0', b'b', ..] => (2, 2),
+        [b'0', b'o', ..] => (8, 2),
+        [b'0', b'x', ..] => (16, 2),
+        _ => (10, 0),
+    };
+
+    // a trailing '%' is handled after the other parts
+    let (s, percent) = match s.split_last() {
+        Some((b'%', rest)) if !rest.is_empty() => (rest, true),
+        _ => (s, false),
+    };
+
     if is_empty_or_all_underscores(s) {
-        return Err(Empty);
+        return err(Empty, 0, false);
     }
 
     // handle sign
     let mut i = 0;
     if s[i] == b'-' {
         if s.len() <= 1 {
-            return Err(EmptyInteger);
+            return err(EmptyInteger, 1, true);
         }
         sign = true;
         i += 1;
@@ -149,27 +937,17 @@ fn common_from_str(
     }
 
     // handle radix
-    if (s[i] == b'0') && ((i + 1) < s.len()) {
-        if s[i + 1] == b'b' {
-            radix = 2;
-            i += 2;
-        } else if s[i + 1] == b'o' {
-            radix = 8;
-            i += 2;
-        } else if s[i + 1] == b'x' {
-            radix = 16;
-            i += 2;
-        } else {
-            radix = 10;
-        }
-    } else {
-        radix = 10;
-    }
+    let prefix_len;
+    (radix, prefix_len) = radix_prefix(&s[i..]);
+    i += prefix_len;
 
     // integer part, can be followed by '.' for fraction, 'e' or 'p' for exponent
+    // (uppercase is also allowed, but in radix 16 'e' and 'E' are digits), or
+    // '/' for a denominator
     let integer_start = i;
     let mut fraction_start = None;
     let mut exp_start = None;
+    let mut denominator_start = None;
     loop {
         if i >= s.len() {
             integer = &s[integer_start..i];
@@ -178,10 +956,12 @@ fn common_from_str(
         if !is_integral(s[i], radix) {
             if s[i] == b'.' {
                 fraction_start = Some(i + 1);
-            } else if (s[i] == b'e') || (s[i] == b'p') {
+            } else if s[i] == b'/' {
+                denominator_start = Some(i + 1);
+            } else if is_exponent_char(s[i]) {
                 exp_start = Some(i + 1);
             } else {
-                return Err(InvalidCharInInteger);
+                return err(InvalidCharInInteger, i, sign);
             }
             integer = &s[integer_start..i];
             i += 1;
@@ -198,10 +978,10 @@ fn common_from_str(
                 break;
             }
             if !is_integral(s[i], radix) {
-                if (s[i] == b'e') || (s[i] == b'p') {
+                if is_exponent_char(s[i]) {
                     exp_start = Some(i + 1);
                 } else {
-                    return Err(InvalidCharInFraction);
+                    return err(InvalidCharInFraction, i, sign);
                 }
                 fraction = Some(&s[fraction_start..i]);
                 i += 1;
@@ -220,118 +1000,549 @@ fn common_from_str(
             if !is_integral(s[i], radix) {
                 if s[i] == b'-' {
                     if exp_negative {
-                        return Err(InvalidCharInExponent);
+                        return err(InvalidCharInExponent, i, sign);
                     }
                     exp_negative = true;
                     exp_start += 1;
                     i += 1;
                     continue;
                 } else {
-                    return Err(InvalidCharInExponent);
+                    return err(InvalidCharInExponent, i, sign);
                 }
             }
             i += 1;
         }
-        exp = Some(&s[exp_start..i]);
+        exp = Some((exp_start, &s[exp_start..i]));
+    }
+
+    // denominator part, which is an integer with its own optional radix prefix
+    let mut denominator = None;
+    if let Some(denominator_start) = denominator_start {
+        let (denominator_radix, prefix_len) = radix_prefix(&s[denominator_start..]);
+        let digits_start = denominator_start + prefix_len;
+        for (j, c) in s.iter().enumerate().skip(digits_start) {
+            if !is_integral(*c, denominator_radix) {
+                return err(InvalidCharInInteger, j, sign);
+            }
+        }
+        if is_empty_or_all_underscores(&s[digits_start..]) {
+            return err(EmptyInteger, digits_start, sign);
+        }
+        denominator = Some((denominator_radix, &s[digits_start..]));
     }
 
     if is_empty_or_all_underscores(integer) {
-        return Err(EmptyInteger);
+        return err(EmptyInteger, integer_start, sign);
     }
 
-    if let Some(fraction) = fraction {
-        if is_empty_or_all_underscores(fraction) {
-            return Err(EmptyFraction);
-        }
+    if let (Some(fraction), Some(fraction_start)) = (fraction, fraction_start)
+        && is_empty_or_all_underscores(fraction)
+    {
+        return err(EmptyFraction, fraction_start, sign);
     }
     let fraction = fraction.unwrap_or(&[]);
 
-    let pad0 = &mut InlAwi::from_usize(0);
-    let pad1 = &mut InlAwi::from_usize(0);
-    let mut usize_awi = InlAwi::from_usize(0);
-    let exp = if let Some(exp) = exp {
+    let has_exp = exp.is_some();
+    let exp = if let Some((exp_start, exp)) = exp {
         if is_empty_or_all_underscores(exp) {
-            return Err(EmptyExponent);
+            return err(EmptyExponent, exp_start, sign);
         }
-        if usize_awi
-            .bytes_radix_(Some(exp_negative), exp, radix, pad0, pad1)
-            .is_err()
-        {
-            return Err(Overflow);
+        // saturation is fine since exponents this large overflow or round to zero
+        let mut e: isize = 0;
+        for (j, c) in exp.iter().enumerate() {
+            if *c == b'-' {
+                // the '-' was not at the start of the exponent
+                return err(InvalidCharInExponent, exp_start + j, sign)
+            }
+            if *c != b'_' {
+                e = e
+                    .saturating_mul(radix as isize)
+                    .saturating_add(digit_value(*c) as isize);
+            }
         }
-        usize_awi.to_isize()
+        if exp_negative { -e } else { e }
     } else {
         0
     };
 
-    // note we handle the sign ourselves, the sign bit is instead room for ONE and
-    // NEG_ONE
-    Ok(Awi::from_bytes_general(
-        None,
+    // a percentage is only allowed for radix 10 without a denominator
+    let exp = if percent {
+        if (radix != 10) || denominator.is_some() {
+            let kind = if has_exp {
+                InvalidCharInExponent
+            } else if !fraction.is_empty() {
+                InvalidCharInFraction
+            } else {
+                InvalidCharInInteger
+            };
+            return err(kind, s.len(), sign);
+        }
+        exp.saturating_sub(2)
+    } else {
+        exp
+    };
+
+    Ok(Parts {
+        sign,
+        start: integer_start,
+        radix,
         integer,
         fraction,
         exp,
-        radix,
-        NonZeroUsize::new(bw as usize).unwrap(),
-        bw - 1,
+        denominator,
+    })
+}
+
+/// Rounds to even given the round bit and whether any bits below it are set,
+/// then checks the bounds
+pub(crate) fn round_to_even<F: FractionBits>(
+    mut int: u8,
+    mut frac: F,
+    round: bool,
+    sticky: bool,
+    fp: usize,
+) -> Result<Option<F>, FracintSerdeError> {
+    if round && (sticky || frac.is_odd()) {
+        if frac == F::mask(fp) {
+            frac = F::ZERO;
+            int += 1;
+        } else {
+            frac = frac.add_one();
+        }
+    }
+    match int {
+        0 => Ok(Some(frac)),
+        1 if frac == F::ZERO => Ok(None),
+        _ => Err(FracintSerdeError::Overflow),
+    }
+}
+
+/// Radixes 2, 8, and 16 can place the bits of the digits directly
+fn from_pow2_digits<F: FractionBits>(
+    digits: impl Iterator<Item = u8>,
+    point: isize,
+    radix: u8,
+    fp: usize,
+) -> Result<Option<F>, FracintSerdeError> {
+    let digit_bits = radix.trailing_zeros() as isize;
+    let mut int = 0;
+    let mut frac = F::ZERO;
+    let mut round = false;
+    let mut sticky = false;
+    // the position of the next bit, where 0 is the units place and 1 is the
+    // first fraction bit
+    let mut pos = 1 - (point * digit_bits);
+    for d in digits {
+        for j in (0..digit_bits).rev() {
+            let bit = (d >> j) & 1;
+            if pos < 0 {
+                if bit != 0 {
+                    return Err(FracintSerdeError::Overflow)
+                }
+            } else if pos == 0 {
+                int = bit;
+            } else if pos <= (fp as isize) {
+                if bit != 0 {
+                    frac = frac.set_bit(fp - (pos as usize));
+                }
+            } else if pos == ((fp as isize) + 1) {
+                round = bit != 0;
+            } else {
+                sticky |= bit != 0;
+            }
+            pos += 1;
+        }
+        if sticky {
+            break
+        }
+    }
+    round_to_even(int, frac, round, sticky, fp)
+}
+
+/// The fast path for up to 19 significant decimal digits and `fp <= 64`,
+/// which can be done exactly with one 128 bit division
+fn from_decimal_digits_fast(
+    significand: u64,
+    num_digits: usize,
+    point: isize,
+    fp: usize,
+) -> Result<Option<u128>, FracintSerdeError> {
+    // the value is `significand / 10^(num_digits - point)`, there is at least
+    // one digit and `point <= 1` so the exponent is not negative
+    let div = 10u128.pow(((num_digits as isize) - point) as u32);
+    let num = (significand as u128) << fp;
+    let mut quo = num / div;
+    let rem = num % div;
+    if ((rem << 1) > div) || (((rem << 1) == div) && ((quo & 1) != 0)) {
+        quo += 1;
+    }
+    round_to_even(
+        (quo >> fp) as u8,
+        quo & (u128::MAX >> (128 - fp)),
+        false,
+        false,
+        fp,
     )
-    .map(|res| (sign, res)))
+}
+
+/// The general path for radix 10, this uses a fixed number of decimal digits
+/// and converts by repeated multiplication
+fn from_decimal_digits<F: FractionBits>(
+    mut digits: impl Iterator<Item = u8>,
+    point: isize,
+    fp: usize,
+) -> Result<Option<F>, FracintSerdeError> {
+    // split into an integer digit and a fixed number of fraction digits
+    let mut int = 0;
+    if point == 1 {
+        int = digits.next().unwrap();
+        if int >= 2 {
+            return Err(FracintSerdeError::Overflow)
+        }
+    }
+    let mut buf = [0u8; MAX_FRACTION_DIGITS];
+    let mut len = point.min(0).unsigned_abs();
+    let mut truncated = false;
+    for d in digits {
+        if len < MAX_FRACTION_DIGITS {
+            buf[len] = d;
+            len += 1;
+        } else if d != 0 {
+            truncated = true;
+            break
+        }
+    }
+
+    // convert the fraction to binary, multiplying by up to `2^24` at a time
+    let mut next_bits = |bits: usize| {
+        let mut carry = 0u32;
+        for d in buf[..len].iter_mut().rev() {
+            let tmp = ((*d as u32) << bits) + carry;
+            *d = (tmp % 10) as u8;
+            carry = tmp / 10;
+        }
+        while (len > 0) && (buf[len - 1] == 0) {
+            len -= 1;
+        }
+        carry as u128
+    };
+    let mut frac = F::ZERO;
+    let mut remaining = fp;
+    while remaining > 0 {
+        let bits = remaining.min(24);
+        frac = frac.shl_or(bits, next_bits(bits));
+        remaining -= bits;
+    }
+    let round = next_bits(1) != 0;
+    let sticky = truncated || (len != 0);
+    round_to_even(int, frac, round, sticky, fp)
+}
+
+/// Returns the value of integer digits, or `None` if it does not fit in a
+/// `u128`
+fn integer_value(digits: &[u8], radix: u8) -> Option<u128> {
+    let mut x = 0u128;
+    for c in digits.iter().filter(|c| **c != b'_') {
+        x = x
+            .checked_mul(radix as u128)?
+            .checked_add(digit_value(*c) as u128)?;
+    }
+    Some(x)
+}
+
+/// Rounds `num / den` to even to `fp` fraction bits with long division
+fn from_ratio<F: FractionBits>(
+    num: u128,
+    den: u128,
+    fp: usize,
+) -> Result<Option<F>, FracintSerdeError> {
+    if den == 0 {
+        return Err(FracintSerdeError::Overflow)
+    }
+    let int = num / den;
+    if int >= 2 {
+        return Err(FracintSerdeError::Overflow)
+    }
+    let mut rem = num % den;
+    // returns the next bit of the quotient
+    let mut next_bit = || {
+        // `rem < den` so this only needs one extra bit
+        let carry = (rem >> 127) != 0;
+        rem <<= 1;
+        if carry || (rem >= den) {
+            rem = rem.wrapping_sub(den);
+            1
+        } else {
+            0
+        }
+    };
+    let mut frac = F::ZERO;
+    for _ in 0..fp {
+        frac = frac.shl_or(1, next_bit());
+    }
+    let round = next_bit() != 0;
+    round_to_even(int as u8, frac, round, rem != 0, fp)
+}
+
+/// Takes an ASCII string and the number of fraction bits (up to `F::BITS`)
+/// that the numerical value should be rounded to. Returns the sign and the
+/// unsigned fraction, or `None` if the rounded magnitude is exactly one.
+#[inline]
+fn common_from_ascii<F: FractionBits>(
+    s: &[u8],
+    fp: usize,
+) -> Result<(bool, Option<F>), FracintParseError> {
+    let Parts {
+        sign,
+        start,
+        radix,
+        integer,
+        fraction,
+        exp,
+        denominator,
+    } = scan(s)?;
+    // the numeric conversions can only return `Overflow`
+    let overflow = |kind| FracintParseError::new(kind, start, sign);
+
+    // note we handle the sign ourselves, the sign bit is instead room for ONE and
+    // NEG_ONE
+
+    if let Some((denominator_radix, denominator)) = denominator {
+        let num = integer_value(integer, radix);
+        let den = integer_value(denominator, denominator_radix);
+        return match (num, den) {
+            (Some(num), Some(den)) => from_ratio(num, den, fp),
+            _ => Err(FracintSerdeError::Overflow),
+        }
+        .map(|x| (sign, x))
+        .map_err(overflow)
+    }
+
+    // the value is `0.d_0 d_1 d_2... * radix^point`
+    let mut digits = integer
+        .iter()
+        .chain(fraction)
+        .filter(|c| **c != b'_')
+        .map(|c| digit_value(*c))
+        .peekable();
+    let integer_len = integer.iter().filter(|c| **c != b'_').count() as isize;
+    let mut point = integer_len.saturating_add(exp);
+    while digits.next_if_eq(&0).is_some() {
+        point = point.saturating_sub(1);
+    }
+    if digits.peek().is_none() || (point < -(MAX_FRACTION_DIGITS as isize)) {
+        return Ok((sign, Some(F::ZERO)))
+    }
+    if point >= 2 {
+        return Err(overflow(FracintSerdeError::Overflow))
+    }
+
+    if radix != 10 {
+        return from_pow2_digits(digits, point, radix, fp)
+            .map(|x| (sign, x))
+            .map_err(overflow)
+    }
+    if fp <= 64 {
+        // try to fit the significant digits in a `u64`, ignoring trailing zeros
+        let mut significand = 0u64;
+        let mut num_digits = 0;
+        let mut zeros = 0;
+        let mut fits = true;
+        for d in digits.clone() {
+            if d == 0 {
+                zeros += 1;
+                continue
+            }
+            num_digits += zeros + 1;
+            if num_digits > 19 {
+                fits = false;
+                break
+            }
+            significand = (significand * 10u64.pow(zeros)) * 10 + (d as u64);
+            zeros = 0;
+        }
+        // `10^38` is the largest power of 10 that fits in a `u128`
+        if fits && (((num_digits as isize) - point) <= 38) {
+            return from_decimal_digits_fast(significand, num_digits as usize, point, fp)
+                .map(|x| (sign, x.map(F::from_u128)))
+                .map_err(overflow)
+        }
+    }
+    from_decimal_digits(digits, point, fp)
+        .map(|x| (sign, x))
+        .map_err(overflow)
 }
 
 macro_rules! impl_signed_conversions {
-($($iX:ident $to_string:ident $from_str:ident $to_iX:ident $from_iX:ident);*;) => {$(
-    /// Conversion of the internal integer of a fracint to a base 10 string
-    pub fn $to_string(x: $iX) -> String {
+($($iX:ident $to_str_buf:ident $from_ascii:ident $from_str:ident $str_len:ident);*;) => {$(
+    #[doc = concat!("The [FracintStrBuf] length for `", stringify!($iX), "` fracints")]
+    pub const $str_len: usize = 3 + unique_fraction_digits(($iX::BITS - 1) as usize);
+
+    /// Conversion of the internal integer of a fracint to a base 10 string in
+    /// a stack buffer
+    pub fn $to_str_buf(x: $iX) -> FracintStrBuf<$str_len> {
         const TMP: $iX = -$iX::MAX;
-        match x {
-            TMP | $iX::MIN => return "-1.0".to_string(),
-            0 => return "0.0".to_string(),
-            $iX::MAX => return "1.0".to_string(),
-            _ => (),
-        }
-        let sign = x < 0;
-        let x = FP::new(true, InlAwi::$from_iX(x), ($iX::BITS - 1) as isize).unwrap();
-        let (int, frac) = FP::to_str_general(&x, 10, false, 1, 1, 4096).unwrap();
-        if sign {
-            format!("-{int}.{frac}")
-        } else {
-            format!("{int}.{frac}")
+        let mut res = FracintStrBuf {
+            buf: [0; $str_len],
+            len: 0,
+        };
+        let s: &[u8] = match x {
+            TMP | $iX::MIN => b"-1.0",
+            0 => b"0.0",
+            $iX::MAX => b"1.0",
+            _ => b"",
+        };
+        if !s.is_empty() {
+            res.buf[..s.len()].copy_from_slice(s);
+            res.len = s.len();
+            return res
+        }
+        let sign = (x < 0) as usize;
+        res.buf[0] = b'-';
+        let frac = (x.unsigned_abs() as u128) << (128 - ($iX::BITS - 1));
+        res.len = sign
+            + write_fraction(
+                &mut res.buf[sign..],
+                frac,
+                unique_fraction_digits(($iX::BITS - 1) as usize),
+            );
+        res
+    }
+
+    /// Conversion from an ASCII string representation to the internal integer
+    /// of a fracint.
+    pub fn $from_ascii(s: &[u8]) -> Result<$iX, FracintParseError> {
+        match common_from_ascii::<u128>(s, ($iX::BITS - 1) as usize)? {
+            // ONE and NEG_ONE special cases
+            (false, None) => Ok($iX::MAX),
+            (true, None) => Ok(-$iX::MAX),
+            (false, Some(x)) => Ok(x as $iX),
+            (true, Some(x)) => Ok(-(x as $iX)),
         }
     }
 
     /// Conversion from a string representation to the internal integer of a
     /// fracint.
-    pub fn $from_str(s: &str) -> Result<$iX, FracintSerdeError> {
-        use FracintSerdeError::*;
-        match common_from_str(s, $iX::BITS as isize)? {
-            Ok((sign, awi)) => {
-                // ONE and NEG_ONE special cases
-                if awi.msb() {
-                    if awi.is_imin() {
-                        if sign {
-                            Ok(-$iX::MAX)
-                        } else {
-                            Ok($iX::MAX)
-                        }
-                    } else {
-                        Err(Overflow)
-                    }
-                } else if sign {
-                    Ok(-awi.$to_iX())
-                } else {
-                    Ok(awi.$to_iX())
-                }
-            }
-            _ => Err(Overflow),
-        }
+    pub fn $from_str(s: &str) -> Result<$iX, FracintParseError> {
+        $from_ascii(s.as_bytes())
     }
 )*}
 }
 
 impl_signed_conversions!(
-    i8 i8_to_string i8_from_str to_i8 from_i8;
-    i16 i16_to_string i16_from_str to_i16 from_i16;
-    i32 i32_to_string i32_from_str to_i32 from_i32;
-    i64 i64_to_string i64_from_str to_i64 from_i64;
-    i128 i128_to_string i128_from_str to_i128 from_i128;
+    i8 i8_to_str_buf i8_from_ascii i8_from_str I8_STR_LEN;
+    i16 i16_to_str_buf i16_from_ascii i16_from_str I16_STR_LEN;
+    i32 i32_to_str_buf i32_from_ascii i32_from_str I32_STR_LEN;
+    i64 i64_to_str_buf i64_from_ascii i64_from_str I64_STR_LEN;
+    i128 i128_to_str_buf i128_from_ascii i128_from_str I128_STR_LEN;
+);
+
+/// The [FracintStrBuf] length for `fi256` fracints
+pub const I256_STR_LEN: usize = 3 + unique_fraction_digits(255);
+
+/// Conversion of the internal integer of a fracint to a base 10 string in a
+/// stack buffer
+pub fn i256_to_str_buf(x: i256) -> FracintStrBuf<I256_STR_LEN> {
+    let mut res = FracintStrBuf {
+        buf: [0; I256_STR_LEN],
+        len: 0,
+    };
+    let s: &[u8] = if (x == i256::MAX.wrapping_neg()) || (x == i256::MIN) {
+        b"-1.0"
+    } else if x == i256::from_i128(0) {
+        b"0.0"
+    } else if x == i256::MAX {
+        b"1.0"
+    } else {
+        b""
+    };
+    if !s.is_empty() {
+        res.buf[..s.len()].copy_from_slice(s);
+        res.len = s.len();
+        return res
+    }
+    let sign = x.is_negative() as usize;
+    res.buf[0] = b'-';
+    let frac = x.unsigned_abs().shl_or(1, 0);
+    res.len = sign + write_fraction(&mut res.buf[sign..], frac, unique_fraction_digits(255));
+    res
+}
+
+/// Conversion from an ASCII string representation to the internal integer of
+/// a fracint.
+pub fn i256_from_ascii(s: &[u8]) -> Result<i256, FracintParseError> {
+    match common_from_ascii::<(u128, u128)>(s, 255)? {
+        // ONE and NEG_ONE special cases
+        (false, None) => Ok(i256::MAX),
+        (true, None) => Ok(i256::MAX.wrapping_neg()),
+        (negative, Some((hi, lo))) => {
+            let x = i256::from_parts(lo, hi as i128);
+            Ok(if negative { x.wrapping_neg() } else { x })
+        }
+    }
+}
+
+/// Conversion from a string representation to the internal integer of a
+/// fracint.
+pub fn i256_from_str(s: &str) -> Result<i256, FracintParseError> {
+    i256_from_ascii(s.as_bytes())
+}
+
+macro_rules! impl_unsigned_conversions {
+($($uX:ident $to_str_buf:ident $from_ascii:ident $from_str:ident $str_len:ident);*;) => {$(
+    #[doc = concat!("The [FracintStrBuf] length for `", stringify!($uX), "` fracints")]
+    pub const $str_len: usize = 2 + unique_fraction_digits($uX::BITS as usize);
+
+    /// Conversion of the internal integer of an unsigned fracint to a base 10
+    /// string in a stack buffer
+    pub fn $to_str_buf(x: $uX) -> FracintStrBuf<$str_len> {
+        let mut res = FracintStrBuf {
+            buf: [0; $str_len],
+            len: 0,
+        };
+        let s: &[u8] = match x {
+            0 => b"0.0",
+            $uX::MAX => b"1.0",
+            _ => b"",
+        };
+        if !s.is_empty() {
+            res.buf[..s.len()].copy_from_slice(s);
+            res.len = s.len();
+            return res
+        }
+        let frac = (x as u128) << (128 - $uX::BITS);
+        res.len = write_fraction(
+            &mut res.buf,
+            frac,
+            unique_fraction_digits($uX::BITS as usize),
+        );
+        res
+    }
+
+    /// Conversion from an ASCII string representation to the internal integer
+    /// of an unsigned fracint.
+    pub fn $from_ascii(s: &[u8]) -> Result<$uX, FracintParseError> {
+        match common_from_ascii::<u128>(s, $uX::BITS as usize)? {
+            // ONE special case
+            (false, None) => Ok($uX::MAX),
+            (_, Some(0)) => Ok(0),
+            (false, Some(x)) => Ok(x as $uX),
+            (true, _) => Err(FracintParseError::new(FracintSerdeError::Overflow, 0, true)),
+        }
+    }
+
+    /// Conversion from a string representation to the internal integer of an
+    /// unsigned fracint.
+    pub fn $from_str(s: &str) -> Result<$uX, FracintParseError> {
+        $from_ascii(s.as_bytes())
+    }
+)*}
+}
+
+impl_unsigned_conversions!(
+    u8 u8_to_str_buf u8_from_ascii u8_from_str U8_STR_LEN;
+    u16 u16_to_str_buf u16_from_ascii u16_from_str U16_STR_LEN;
+    u32 u32_to_str_buf u32_from_ascii u32_from_str U32_STR_LEN;
+    u64 u64_to_str_buf u64_from_ascii u64_from_str U64_STR_LEN;
+    u128 u128_to_str_buf u128_from_ascii u128_from_str U128_STR_LEN;
 );
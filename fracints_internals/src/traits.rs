@@ -7,6 +7,22 @@ use core::{
 
 // TODO decide on how `to_string_general` should work
 
+/// The direction that an inexact result is rounded in, used by
+/// [Fracint::mul_with] and [Fracint::div_with]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round towards negative infinity. This is what `saturating_mul` does,
+    /// except for `fi128` which truncates products towards zero.
+    Floor,
+    /// Round towards positive infinity
+    Ceil,
+    /// Round towards zero. This is what `saturating_div` does.
+    TowardZero,
+    /// Round to the nearest value, with exact ties rounding to the value with
+    /// an even last bit. This has no bias on average.
+    NearestEven,
+}
+
 /// A common trait for a special case of fixed point numbers in the form of all
 /// fractional bits.
 pub trait Fracint:
@@ -70,6 +86,9 @@ pub trait Fracint:
     /// The purpose of `NEG_ONE` in contrast to `MIN` is preventing
     /// certain overflows, such as `fiN::MIN.wrapping_mul(fiN::MIN)` or
     /// `fiN::wrapping_abs(fiN::MIN)`.
+    ///
+    /// Negative values are not representable by `fuN`, so for those types
+    /// `NEG_ONE`, `MIN`, and `ZERO` are all the same.
     const NEG_ONE: Self;
     /// One positive Unit in the Last Place
     const ULP: Self;
@@ -257,6 +276,157 @@ pub trait Fracint:
     /// numeric bounds `fiN::NEG_ONE` and `fiN::ONE` instead of overflowing.
     fn saturating_mul_int(self, rhs: Self::Int) -> Self;
 
+    /// Saturating fracint multiplication rounded according to `mode`. This
+    /// has the same corner case as `saturating_mul`, and is the same as
+    /// `saturating_mul` for `RoundingMode::Floor` (`RoundingMode::TowardZero`
+    /// for `fi128`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fracints::*;
+    ///
+    /// let x = fi8(-3);
+    /// let y = fi8!(0.5);
+    /// // `x * y` is exactly -1.5 ULPs
+    /// assert_eq!(x.mul_with(y, RoundingMode::Floor), fi8(-2));
+    /// assert_eq!(x.mul_with(y, RoundingMode::Ceil), fi8(-1));
+    /// assert_eq!(x.mul_with(y, RoundingMode::TowardZero), fi8(-1));
+    /// assert_eq!(x.mul_with(y, RoundingMode::NearestEven), fi8(-2));
+    /// ```
+    fn mul_with(self, rhs: Self, mode: RoundingMode) -> Self;
+
+    /// Same as `mul_with` with `RoundingMode::NearestEven`
+    fn mul_round(self, rhs: Self) -> Self {
+        self.mul_with(rhs, RoundingMode::NearestEven)
+    }
+
+    /// Same as `mul_with` with `RoundingMode::Ceil`
+    fn mul_ceil(self, rhs: Self) -> Self {
+        self.mul_with(rhs, RoundingMode::Ceil)
+    }
+
+    /// Wrapping fused multiply-add, computing `self * a + b` rounded once
+    /// towards negative infinity, the same as
+    /// `self.mul_with(a, RoundingMode::Floor).wrapping_add(b)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fracints::*;
+    ///
+    /// let (x, a, b) = (fi8(-3), fi8!(0.5), fi8(2));
+    /// // `x * a + b` is exactly 0.5 ULP
+    /// assert_eq!(x.wrapping_mul_add(a, b), fi8::ZERO);
+    /// ```
+    ///
+    /// # Overflow behavior
+    ///
+    /// This overflows in the same way as `wrapping_add` does. The
+    /// intermediate product can be `fiN::MIN * fiN::MIN`, which is equal to
+    /// `fiN::ONE + fiN::ULP`.
+    fn wrapping_mul_add(self, a: Self, b: Self) -> Self {
+        // modular arithmetic makes an intermediate overflow irrelevant
+        match self.overflowing_mul(a) {
+            (_, false) => self.mul_with(a, RoundingMode::Floor).wrapping_add(b),
+            (p, true) => p.wrapping_add(b),
+        }
+    }
+
+    /// Returns a tuple of `self.wrapping_mul_add(a, b)` along with a boolean
+    /// indicating whether an overflow happened. Only the numerical value of
+    /// the final result matters, so `fiN::MIN * fiN::MIN + fiN::NEG_ONE` does
+    /// not overflow.
+    /// Note that if the result is `fiN::MIN`, it is counted as overflow.
+    fn overflowing_mul_add(self, a: Self, b: Self) -> (Self, bool) {
+        match self.overflowing_mul(a) {
+            (_, false) => self.mul_with(a, RoundingMode::Floor).overflowing_add(b),
+            // the only overflowing product is `fiN::MIN * fiN::MIN`, which is numerically
+            // `-fiN::MIN`
+            (_, true) => (b.wrapping_sub(Self::MIN), !b.is_negative()),
+        }
+    }
+
+    /// Same as `overflowing_mul_add` except it returns `None` on overflow
+    fn checked_mul_add(self, a: Self, b: Self) -> Option<Self> {
+        match self.overflowing_mul_add(a, b) {
+            (v, false) => Some(v),
+            (_, true) => None,
+        }
+    }
+
+    /// Saturating fused multiply-add. Saturates at the numeric bounds
+    /// `fiN::NEG_ONE` and `fiN::ONE` instead of overflowing, unlike
+    /// `self.saturating_mul(a).saturating_add(b)` this only saturates the
+    /// final result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fracints::*;
+    ///
+    /// let x = fi32!(-0.75);
+    /// assert_eq!(fi32::MIN.saturating_mul_add(fi32::MIN, x), fi32!(0.25));
+    /// assert_eq!(
+    ///     fi32::MIN.saturating_mul(fi32::MIN).saturating_add(x),
+    ///     fi32!(0.25) - fi32::ULP
+    /// );
+    /// assert_eq!(fi32!(0.75).saturating_mul_add(fi32!(0.5), x), fi32!(-0.375));
+    /// assert_eq!(
+    ///     fi32!(0.75).saturating_mul_add(fi32!(-0.5), x),
+    ///     fi32::NEG_ONE
+    /// );
+    /// ```
+    fn saturating_mul_add(self, a: Self, b: Self) -> Self {
+        match self.overflowing_mul(a) {
+            (_, false) => self.mul_with(a, RoundingMode::Floor).saturating_add(b),
+            (_, true) => {
+                if b.is_negative() {
+                    b.wrapping_sub(Self::MIN)
+                } else {
+                    Self::ONE
+                }
+            }
+        }
+    }
+
+    /// Wrapping (modular) fracint division. The quotient is truncated towards
+    /// zero, and the bits above `fiN::BITS` are discarded.
+    ///
+    /// # Overflow behavior
+    ///
+    /// Overflow happens if `self.abs() >= rhs.abs()`, because the numeric
+    /// quotient is then not within the exclusive range `(-1, 1)`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs == fiN::ZERO`.
+    fn wrapping_div(self, rhs: Self) -> Self {
+        self.div_rem(rhs).0
+    }
+
+    /// Returns a tuple of `self.wrapping_div(rhs)` along with a boolean
+    /// indicating whether an overflow happened.
+    /// Note that if the quotient is numerically equal to `fiN::MIN`, it is
+    /// counted as overflow.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs == fiN::ZERO`.
+    fn overflowing_div(self, rhs: Self) -> (Self, bool);
+
+    /// Same as `overflowing_div` except it returns `None` on overflow or if
+    /// `rhs == fiN::ZERO`
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None
+        }
+        match self.overflowing_div(rhs) {
+            (v, false) => Some(v),
+            (_, true) => None,
+        }
+    }
+
     /// Saturating fracint division. Saturates at the numeric bounds
     /// `fiN::NEG_ONE` and `fiN::ONE` instead of overflowing.
     ///
@@ -269,6 +439,42 @@ pub trait Fracint:
     /// Note that it does not panic on zero
     fn saturating_div(self, rhs: Self) -> Self;
 
+    /// Returns a tuple of `self.wrapping_div(rhs)` and the exact remainder.
+    /// The remainder is in units of `fiN::ULP * fiN::ULP`, so that numerically
+    /// `self == quo * rhs + rem * fiN::ULP` if the quotient did not overflow.
+    /// The remainder has the same sign as `self` and is always smaller in
+    /// magnitude than `rhs`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs == fiN::ZERO`.
+    fn div_rem(self, rhs: Self) -> (Self, Self);
+
+    /// Wrapping fracint division with an integer. The quotient is truncated
+    /// towards zero.
+    ///
+    /// # Overflow behavior
+    ///
+    /// There is only one case where overflow can occur:
+    /// `fiN::MIN.wrapping_div_int(-1)` -> `fiN::MIN`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs == 0`.
+    fn wrapping_div_int(self, rhs: Self::Int) -> Self;
+
+    /// Returns a tuple of `self.wrapping_div_int(rhs)` along with a boolean
+    /// indicating whether an overflow happened.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs == 0`.
+    fn overflowing_div_int(self, rhs: Self::Int) -> (Self, bool);
+
+    /// Same as `overflowing_div_int` except it returns `None` on overflow or
+    /// if `rhs == 0`
+    fn checked_div_int(self, rhs: Self::Int) -> Option<Self>;
+
     /// Saturating fracint division with an integer. Saturates at the numeric
     /// bounds `fiN::NEG_ONE` and `fiN::ONE` instead of overflowing.
     ///
@@ -277,6 +483,32 @@ pub trait Fracint:
     /// - if `self == fiN::MIN` and `rhs == -1`, `Self::ONE` is returned
     fn saturating_div_int(self, rhs: Self::Int) -> Self;
 
+    /// Returns a tuple of `self.wrapping_div_int(rhs)` and the exact
+    /// remainder, so that numerically `self == quo * rhs + rem`. The remainder
+    /// has the same sign as `self` and is always smaller in magnitude than
+    /// `rhs` ULPs.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs == 0`.
+    fn div_rem_int(self, rhs: Self::Int) -> (Self, Self);
+
+    /// Saturating fracint division rounded according to `mode`. This has the
+    /// same special cases as `saturating_div`, and is the same as
+    /// `saturating_div` for `RoundingMode::TowardZero`. Quotients that round
+    /// to a magnitude of 1 are saturated to `fiN::NEG_ONE` or `fiN::ONE`.
+    fn div_with(self, rhs: Self, mode: RoundingMode) -> Self;
+
+    /// Same as `div_with` with `RoundingMode::NearestEven`
+    fn div_round(self, rhs: Self) -> Self {
+        self.div_with(rhs, RoundingMode::NearestEven)
+    }
+
+    /// Same as `div_with` with `RoundingMode::Ceil`
+    fn div_ceil(self, rhs: Self) -> Self {
+        self.div_with(rhs, RoundingMode::Ceil)
+    }
+
     #[cfg(feature = "rand_support")]
     fn rand<R: rand_core::RngCore + ?Sized>(rng: &mut R) -> Self;
 
@@ -329,8 +561,27 @@ pub trait Fracint:
     /// Fast way of calculating the square root to within a few ULPs of the true
     /// value.
     fn sqrt_fast(self) -> Self;
+
+    /// Calculates `e^(-self)` for `0 <= self < 1`, which is in the range
+    /// `(0.367, 1]` with `ONE` representing one. Nonpositive inputs return
+    /// `ONE`.
+    ///
+    /// The result is within 1 ULP of the true value, except for `fi256` which
+    /// has no larger type to calculate in and is within 16 ULPs.
+    fn exp_neg(self) -> Self;
+
+    /// Calculates `ln(1 + self)`, which is in the range `(-inf, 0.694)`. Signed
+    /// types saturate to `NEG_ONE` for inputs less than about -0.632.
+    ///
+    /// The result is within 1 ULP of the true value, except for `fi256` which
+    /// has no larger type to calculate in and is within 256 ULPs.
+    fn ln_1p(self) -> Self;
 }
 
+/// A marker trait for the signed `fiN` fracints, which have a negative range
+/// and the `fiN::MIN` corner case
+pub trait FracintSigned: Fracint {}
+
 pub trait FracintDouble: Fracint {
     /// The double-sized version of `Self`
     type Double: Fracint + FracintHalf<Half = Self>;
@@ -0,0 +1,590 @@
+#[macro_export]
+macro_rules! impl_unsigned {
+    (
+        $ty:ident,
+        $s:expr,
+        $uX:ident,
+        $to_str_buf:ident,
+        $from_ascii:ident,
+        $str_len:ident,
+        $normalized_mul:expr,
+        $normalized_div_rem:expr,
+        $sqrt_fast:expr,
+        $exp_neg:expr,
+        $ln_1p:expr,
+        $n:expr,
+        $to_int:ident
+    ) => {
+        // TODO make inner type private, not doing this currently because we need const
+        // traits
+        /// Unsigned Fractional Integer of the unit range.
+        ///
+        /// Note: in the future we want to make the inner type private, but not
+        /// currently because we don't have stable const traits.
+        #[allow(non_camel_case_types)]
+        #[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "serde_support", serde(transparent))]
+        #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $ty(pub $uX);
+
+        impl Fracint for $ty {
+            type Int = $uX;
+
+            const BITS: usize = $uX::BITS as usize;
+            const MAX: Self = Self($uX::MAX);
+            const MIN: Self = Self(0);
+            // negative numbers are not representable, this is the closest value
+            const NEG_ONE: Self = Self(0);
+            const ONE: Self = Self($uX::MAX);
+            const SIGNED: bool = false;
+            const ULP: Self = Self(1);
+            const ZERO: Self = Self(0);
+
+            fn from_int(x: Self::Int) -> Self {
+                Self(x)
+            }
+
+            fn as_int(self) -> Self::Int {
+                self.0
+            }
+
+            fn overflowing_abs(self) -> (Self, bool) {
+                (self, false)
+            }
+
+            fn wrapping_neg(self) -> Self {
+                Self(self.0.wrapping_neg())
+            }
+
+            fn overflowing_neg(self) -> (Self, bool) {
+                (self.wrapping_neg(), self != Self::ZERO)
+            }
+
+            fn saturating_neg(self) -> Self {
+                Self::ZERO
+            }
+
+            fn saturating_inv(self) -> $uX {
+                $uX::MAX.checked_div(self.0).unwrap_or($uX::MAX)
+            }
+
+            fn wrapping_add(self, rhs: Self) -> Self {
+                Self(self.0.wrapping_add(rhs.0))
+            }
+
+            fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                let (internal, overflow) = self.0.overflowing_add(rhs.0);
+                (Self(internal), overflow)
+            }
+
+            fn saturating_add(self, rhs: Self) -> Self {
+                Self(self.0.saturating_add(rhs.0))
+            }
+
+            fn wrapping_sub(self, rhs: Self) -> Self {
+                Self(self.0.wrapping_sub(rhs.0))
+            }
+
+            fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                let (internal, overflow) = self.0.overflowing_sub(rhs.0);
+                (Self(internal), overflow)
+            }
+
+            fn saturating_sub(self, rhs: Self) -> Self {
+                Self(self.0.saturating_sub(rhs.0))
+            }
+
+            fn wrapping_mul(self, rhs: Self) -> Self {
+                Self($normalized_mul(self.0, rhs.0).0)
+            }
+
+            fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+                // unsigned fracint multiplication can never overflow
+                (self.wrapping_mul(rhs), false)
+            }
+
+            fn saturating_mul(self, rhs: Self) -> Self {
+                self.wrapping_mul(rhs)
+            }
+
+            fn saturating_mul_int(self, rhs: $uX) -> Self {
+                Self(self.0.saturating_mul(rhs))
+            }
+
+            fn mul_with(self, rhs: Self, mode: RoundingMode) -> Self {
+                // the truncated product and the discarded bits
+                let (floor, frac) = $normalized_mul(self.0, rhs.0);
+                const HALF: $uX = 1 << ($uX::BITS - 1);
+                let up = match mode {
+                    RoundingMode::Floor | RoundingMode::TowardZero => false,
+                    RoundingMode::Ceil => frac != 0,
+                    RoundingMode::NearestEven => {
+                        (frac > HALF) || ((frac == HALF) && ((floor & 1) != 0))
+                    }
+                };
+                // the product is always less than `ONE`, so this cannot overflow
+                Self(floor + (up as $uX))
+            }
+
+            fn saturating_div(self, rhs: Self) -> Self {
+                if rhs == Self::ZERO {
+                    self.signum()
+                } else if self >= rhs {
+                    Self::ONE
+                } else {
+                    self.wrapping_div(rhs)
+                }
+            }
+
+            fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+                (self.wrapping_div(rhs), self >= rhs)
+            }
+
+            fn div_rem(self, rhs: Self) -> (Self, Self) {
+                if rhs == Self::ZERO {
+                    panic!("attempt to divide by zero")
+                }
+                let (quo, rem) = $normalized_div_rem(self.0, rhs.0);
+                (Self(quo), Self(rem))
+            }
+
+            fn wrapping_div_int(self, rhs: Self::Int) -> Self {
+                Self(self.0 / rhs)
+            }
+
+            fn overflowing_div_int(self, rhs: Self::Int) -> (Self, bool) {
+                // unsigned integer division can never overflow
+                (Self(self.0 / rhs), false)
+            }
+
+            fn checked_div_int(self, rhs: Self::Int) -> Option<Self> {
+                self.0.checked_div(rhs).map(Self)
+            }
+
+            fn saturating_div_int(self, rhs: Self::Int) -> Self {
+                if rhs == 0 {
+                    self.signum()
+                } else {
+                    Self(self.0 / rhs)
+                }
+            }
+
+            fn div_rem_int(self, rhs: Self::Int) -> (Self, Self) {
+                (Self(self.0 / rhs), Self(self.0 % rhs))
+            }
+
+            fn div_with(self, rhs: Self, mode: RoundingMode) -> Self {
+                if (rhs == Self::ZERO) || (self >= rhs) {
+                    return self.saturating_div(rhs)
+                }
+                let (quo, rem) = self.div_rem(rhs);
+                let up = match mode {
+                    RoundingMode::Floor | RoundingMode::TowardZero => false,
+                    RoundingMode::Ceil => rem.0 != 0,
+                    RoundingMode::NearestEven => match rem.0.cmp(&(rhs.0 - rem.0)) {
+                        Ordering::Less => false,
+                        Ordering::Equal => (quo.0 & 1) != 0,
+                        Ordering::Greater => true,
+                    },
+                };
+                Self(quo.0.saturating_add(up as $uX))
+            }
+
+            fn sqrt_fast(self) -> Self {
+                $sqrt_fast(self)
+            }
+
+            fn exp_neg(self) -> Self {
+                $exp_neg(self)
+            }
+
+            fn ln_1p(self) -> Self {
+                $ln_1p(self)
+            }
+
+            /// Generates a random fracint from the given entropy.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use fracints::*;
+            ///
+            /// let mut rng = rand::rng();
+            /// println!("{}", fu128::rand(&mut rng));
+            /// ```
+            #[cfg(feature = "rand_support")]
+            fn rand<R: rand_core::RngCore + ?Sized>(rng: &mut R) -> Self {
+                let mut dst = Self::ZERO.0.to_le_bytes();
+                rng.fill_bytes(&mut dst);
+                Self(Self::Int::from_le_bytes(dst))
+            }
+
+            /// Converts from an `f32` to `Self`. Returns `None` if the value is
+            /// negative or greater than 1.0.
+            fn from_f32(f: f32) -> Option<Self> {
+                if !(0.0..=1.0).contains(&f) {
+                    return None
+                }
+                if f == 1.0 {
+                    return Some(Self::ONE);
+                }
+                let mut x = <inlawi_ty!($n)>::zero();
+                f32_to_fixed(&mut x, $n, f);
+                Some(Self::from_int(x.$to_int()))
+            }
+
+            /// Converts from an `f64` to `Self`. Returns `None` if the value is
+            /// negative or greater than 1.0.
+            fn from_f64(f: f64) -> Option<Self> {
+                if !(0.0..=1.0).contains(&f) {
+                    return None
+                }
+                if f == 1.0 {
+                    return Some(Self::ONE);
+                }
+                let mut x = <inlawi_ty!($n)>::zero();
+                f64_to_fixed(&mut x, $n, f);
+                Some(Self::from_int(x.$to_int()))
+            }
+
+            fn to_f32(self) -> f32 {
+                let mut x: inlawi_ty!($n) = InlAwi::from(self.as_int());
+                fixed_to_f32(&mut x, false, $n)
+            }
+
+            fn to_f64(self) -> f64 {
+                let mut x: inlawi_ty!($n) = InlAwi::from(self.as_int());
+                fixed_to_f64(&mut x, false, $n)
+            }
+        }
+
+        impl $ty {
+            /// Converts to a base 10 string representation in a stack buffer,
+            /// without allocation. This is the same string that the `Display`
+            /// implementation writes. `fuN::ONE` is special cased to "1.0".
+            pub fn to_str_buf(self) -> FracintStrBuf<$str_len> {
+                $to_str_buf(self.0)
+            }
+
+            /// Converts to the shortest base 10 string that parses back to
+            /// `self` in a stack buffer. This is the same as formatting
+            /// with `{:#}`.
+            pub fn to_str_buf_shortest(self) -> FracintStrBuf<$str_len> {
+                let mut s = FracintStrBuf::new();
+                // the buffer is always large enough
+                fmt::Write::write_fmt(&mut s, format_args!("{self:#}")).unwrap();
+                s
+            }
+
+            /// Converts to the shortest base 10 string that parses back to
+            /// `self`, the same as formatting with `{:#}`.
+            #[cfg(feature = "std")]
+            pub fn to_string_shortest(self) -> String {
+                format!("{self:#}")
+            }
+
+            /// Converts to a string representation in `radix`. Radixes 2, 8, and
+            /// 16 are exact and use the same prefixes as `FromStr`, other radixes
+            /// use enough digits to uniquely represent every value.
+            ///
+            /// # Panics
+            ///
+            /// If `radix` is not in the range `2..=36`
+            #[cfg(feature = "std")]
+            pub fn to_string_radix(self, radix: u32) -> String {
+                let frac = (self.0 as u128) << (128 - $uX::BITS);
+                to_str_buf_radix(false, false, frac, $uX::BITS as usize, radix).to_string()
+            }
+
+            /// Conversion from an ASCII byte string representation, using the
+            /// same grammar and rounding as the `FromStr` implementation.
+            pub fn from_ascii(s: &[u8]) -> Result<Self, FracintParseError> {
+                $from_ascii(s).map(Self)
+            }
+        }
+
+        impl fmt::Debug for $ty {
+            /// Converts to a base 10 string representation
+            ///
+            /// `fuN::ONE` is special cased to "1.0".
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{}({})", stringify!($ty), $to_str_buf(self.0))
+            }
+        }
+
+        impl fmt::Display for $ty {
+            /// Converts to a base 10 string representation
+            ///
+            /// `fuN::ONE` is special cased to "1.0". The alternate flag `{:#}`
+            /// uses the shortest string that parses back to the same value. A
+            /// precision rounds to even to that many fraction digits, and the
+            /// width, fill, alignment, '+', and '0' flags are supported the same
+            /// way as for floats.
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let x = self.0;
+                let frac = (x as u128) << (128 - $uX::BITS);
+                fmt_fraction(f, false, x == $uX::MAX, frac, $uX::BITS as usize)
+            }
+        }
+
+        impl fmt::LowerExp for $ty {
+            /// Converts to a base 10 scientific notation string representation
+            ///
+            /// `fuN::ONE` is special cased to "1e0". Without a precision the
+            /// shortest digits that parse back to the same value are used,
+            /// otherwise it rounds to even to that many digits after the leading
+            /// digit.
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let x = self.0;
+                let frac = (x as u128) << (128 - $uX::BITS);
+                fmt_fraction_exp(f, false, x == $uX::MAX, frac, $uX::BITS as usize, false)
+            }
+        }
+
+        impl fmt::UpperExp for $ty {
+            /// The same as `LowerExp` except with an 'E'
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let x = self.0;
+                let frac = (x as u128) << (128 - $uX::BITS);
+                fmt_fraction_exp(f, false, x == $uX::MAX, frac, $uX::BITS as usize, true)
+            }
+        }
+
+        $crate::impl_unsigned!(@radix $ty, $uX, fmt::Binary, 2, false);
+        $crate::impl_unsigned!(@radix $ty, $uX, fmt::Octal, 8, false);
+        $crate::impl_unsigned!(@radix $ty, $uX, fmt::LowerHex, 16, false);
+        $crate::impl_unsigned!(@radix $ty, $uX, fmt::UpperHex, 16, true);
+
+        impl FromStr for $ty {
+            type Err = FracintParseError;
+
+            /// Conversion from a string representation.
+            ///
+            /// This uses the same grammar as `fiN::from_str`. The number must be
+            /// in the range `[0.0,1.0)` or else an overflow error is returned
+            /// ("-0" is allowed). 1.0 is special cased to map to `fuN::ONE`
+            /// even though it is not exactly representable.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $from_ascii(s.as_bytes()).map(Self)
+            }
+        }
+
+        impl Neg for $ty {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                self.saturating_neg()
+            }
+        }
+
+        impl Add for $ty {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                self.saturating_add(rhs)
+            }
+        }
+
+        impl AddAssign for $ty {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl Sub for $ty {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                self.saturating_sub(rhs)
+            }
+        }
+
+        impl SubAssign for $ty {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl Mul for $ty {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self::Output {
+                self.saturating_mul(rhs)
+            }
+        }
+
+        impl Mul<$uX> for $ty {
+            type Output = Self;
+
+            fn mul(self, rhs: $uX) -> Self::Output {
+                self.saturating_mul_int(rhs)
+            }
+        }
+
+        impl MulAssign for $ty {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl MulAssign<$uX> for $ty {
+            fn mul_assign(&mut self, rhs: $uX) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl Div for $ty {
+            type Output = $ty;
+
+            fn div(self, rhs: Self) -> Self {
+                self.saturating_div(rhs)
+            }
+        }
+
+        impl Div<$uX> for $ty {
+            type Output = Self;
+
+            fn div(self, rhs: $uX) -> Self::Output {
+                self.saturating_div_int(rhs)
+            }
+        }
+
+        impl DivAssign for $ty {
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl DivAssign<$uX> for $ty {
+            fn div_assign(&mut self, rhs: $uX) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl Shl<usize> for $ty {
+            type Output = Self;
+
+            fn shl(self, rhs: usize) -> Self {
+                $ty(self.0 << rhs)
+            }
+        }
+
+        impl ShlAssign<usize> for $ty {
+            fn shl_assign(&mut self, rhs: usize) {
+                self.0 <<= rhs
+            }
+        }
+
+        impl Shr<usize> for $ty {
+            type Output = Self;
+
+            fn shr(self, rhs: usize) -> Self {
+                $ty(self.0 >> rhs)
+            }
+        }
+
+        impl ShrAssign<usize> for $ty {
+            fn shr_assign(&mut self, rhs: usize) {
+                self.0 >>= rhs
+            }
+        }
+
+        impl Not for $ty {
+            type Output = Self;
+
+            fn not(self) -> Self {
+                $ty(!self.0)
+            }
+        }
+
+        impl BitOr for $ty {
+            type Output = Self;
+
+            fn bitor(self, rhs: Self) -> Self {
+                $ty(self.0 | rhs.0)
+            }
+        }
+
+        impl BitOrAssign for $ty {
+            fn bitor_assign(&mut self, rhs: Self) {
+                *self = *self | rhs;
+            }
+        }
+
+        impl BitAnd for $ty {
+            type Output = Self;
+
+            fn bitand(self, rhs: Self) -> Self {
+                $ty(self.0 & rhs.0)
+            }
+        }
+
+        impl BitAndAssign for $ty {
+            fn bitand_assign(&mut self, rhs: Self) {
+                *self = *self & rhs;
+            }
+        }
+
+        impl BitXor for $ty {
+            type Output = Self;
+
+            fn bitxor(self, rhs: Self) -> Self {
+                $ty(self.0 ^ rhs.0)
+            }
+        }
+
+        impl BitXorAssign for $ty {
+            fn bitxor_assign(&mut self, rhs: Self) {
+                *self = *self ^ rhs;
+            }
+        }
+
+        impl Sum for $ty {
+            fn sum<I>(iter: I) -> Self
+            where
+                I: Iterator<Item = Self>,
+            {
+                iter.fold(Self::ZERO, Add::add)
+            }
+        }
+
+        impl Product for $ty {
+            fn product<I>(iter: I) -> Self
+            where
+                I: Iterator<Item = Self>,
+            {
+                iter.fold(Self::ONE, Mul::mul)
+            }
+        }
+
+        impl<'a> Sum<&'a $ty> for $ty {
+            fn sum<I>(iter: I) -> Self
+            where
+                I: Iterator<Item = &'a Self>,
+            {
+                iter.fold(Self::ZERO, |acc, elem| acc + *elem)
+            }
+        }
+
+        impl<'a> Product<&'a $ty> for $ty {
+            fn product<I>(iter: I) -> Self
+            where
+                I: Iterator<Item = &'a Self>,
+            {
+                iter.fold(Self::ONE, |acc, elem| acc * *elem)
+            }
+        }
+    };
+    (@radix $ty:ident, $uX:ident, $trait:path, $radix:expr, $upper:expr) => {
+        impl $trait for $ty {
+            /// Converts to an exact string representation in the radix with the
+            /// prefix that `FromStr` uses. A precision rounds to even to that
+            /// many fraction digits.
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let frac = (self.0 as u128) << (128 - $uX::BITS);
+                let digits = const { unique_fraction_digits_radix($uX::BITS as usize, $radix) };
+                fmt_fraction_radix(f, false, false, frac, $radix, $upper, digits)
+            }
+        }
+    };
+}
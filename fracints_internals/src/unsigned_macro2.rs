@@ -0,0 +1,36 @@
+#[macro_export]
+macro_rules! impl_unsigned_double {
+    ($ty:ident, $tyD:ident, $uX:ident, $uD:ident) => {
+        impl FracintDouble for $ty {
+            type Double = $tyD;
+
+            fn widen(self) -> $tyD {
+                $tyD($uD::from(self.0) << $ty::BITS)
+            }
+
+            fn saturating_widening_mul(self, rhs: Self) -> $tyD {
+                // unsigned products are always exact
+                $tyD($uD::from(self.0).wrapping_mul($uD::from(rhs.0)))
+            }
+        }
+
+        impl FracintHalf for $tyD {
+            type Half = $ty;
+
+            fn split(self) -> ($ty, $ty) {
+                ($ty(self.0 as $uX), $ty((self.0 >> $ty::BITS) as $uX))
+            }
+
+            fn truncate(self) -> $ty {
+                $ty((self.0 >> $ty::BITS) as $uX)
+            }
+        }
+
+        impl From<$ty> for $tyD {
+            /// Lossless conversion
+            fn from(x: $ty) -> Self {
+                $tyD($uD::from(x.0) << $ty::BITS)
+            }
+        }
+    };
+}
@@ -0,0 +1,441 @@
+use core::{fmt, num::TryFromIntError, ops::*};
+
+use awint::{Bits, InlAwi, inlawi_ty};
+#[cfg(feature = "serde_support")]
+use serde::{Deserialize, Serialize};
+
+use crate::FractionBits;
+
+/// The operations that `impl_signed!` needs from the inner integer of a signed
+/// fracint that are not shared by the inherent methods of the primitive
+/// integers and [i256]
+#[doc(hidden)]
+pub trait FracintInt: Copy + Ord {
+    /// The magnitude type, which can hold the absolute value of any integer
+    type Frac: FractionBits;
+
+    /// The integer 0
+    const ZERO: Self;
+    /// The integer 1
+    const ONE: Self;
+    /// The integer -1
+    const NEG_ONE: Self;
+
+    /// Returns if the least significant bit is set
+    fn is_odd(self) -> bool;
+
+    /// Returns the absolute value, zero extended
+    fn abs_frac(self) -> Self::Frac;
+
+    /// Returns the absolute value shifted so that `2^(BITS - 1)` is the most
+    /// significant bit of `Self::Frac`, which is a fraction for formatting
+    fn magnitude(self) -> Self::Frac;
+
+    /// Converts from the least significant bits of `x`
+    fn from_bits(x: &Bits) -> Self;
+}
+
+macro_rules! impl_fracint_int {
+    ($($iX:ident, $to_int:ident);*;) => {$(
+        impl FracintInt for $iX {
+            type Frac = u128;
+
+            const NEG_ONE: Self = -1;
+            const ONE: Self = 1;
+            const ZERO: Self = 0;
+
+            fn is_odd(self) -> bool {
+                (self & 1) != 0
+            }
+
+            fn abs_frac(self) -> u128 {
+                self.unsigned_abs() as u128
+            }
+
+            fn magnitude(self) -> u128 {
+                self.abs_frac() << (129 - $iX::BITS)
+            }
+
+            fn from_bits(x: &Bits) -> Self {
+                x.$to_int()
+            }
+        }
+    )*};
+}
+
+impl_fracint_int!(
+    i8, to_i8;
+    i16, to_i16;
+    i32, to_i32;
+    i64, to_i64;
+    i128, to_i128;
+);
+
+/// Truncating conversion, the same as an `as` cast between primitive integers
+#[doc(hidden)]
+pub trait WrappingFrom<T> {
+    fn wrapping_from(x: T) -> Self;
+}
+
+macro_rules! impl_wrapping_from {
+    ($($iX:ident, $iD:ident);*;) => {$(
+        impl WrappingFrom<$iD> for $iX {
+            fn wrapping_from(x: $iD) -> Self {
+                x as $iX
+            }
+        }
+    )*};
+}
+
+impl_wrapping_from!(
+    i8, i16;
+    i16, i32;
+    i32, i64;
+    i64, i128;
+);
+
+impl WrappingFrom<i256> for i128 {
+    fn wrapping_from(x: i256) -> Self {
+        x.lo as i128
+    }
+}
+
+/// A 256 bit signed integer, which is the internal integer of `fi256`. This
+/// only has the subset of the primitive integer operations that `fi256` needs,
+/// and converts to `awint` types for multiplication and division.
+#[allow(non_camel_case_types)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct i256 {
+    // the field order makes the derived ordering numerical
+    hi: i128,
+    lo: u128,
+}
+
+impl i256 {
+    /// The size of this integer type in bits
+    pub const BITS: u32 = 256;
+    /// The largest value that can be represented by this integer type
+    pub const MAX: Self = Self::from_parts(u128::MAX, i128::MAX);
+    /// The smallest value that can be represented by this integer type
+    pub const MIN: Self = Self::from_parts(0, i128::MIN);
+
+    /// Creates the integer `hi * 2^128 + lo`
+    pub const fn from_parts(lo: u128, hi: i128) -> Self {
+        Self { hi, lo }
+    }
+
+    /// Returns the low 128 bits
+    pub const fn lo(self) -> u128 {
+        self.lo
+    }
+
+    /// Returns the high 128 bits, which include the sign bit
+    pub const fn hi(self) -> i128 {
+        self.hi
+    }
+
+    /// Sign extends `x`
+    pub const fn from_i128(x: i128) -> Self {
+        Self::from_parts(x as u128, x >> 127)
+    }
+
+    /// Returns if `self` is negative
+    pub const fn is_negative(self) -> bool {
+        self.hi < 0
+    }
+
+    /// Returns the number of leading zeros
+    pub const fn leading_zeros(self) -> u32 {
+        if self.hi == 0 {
+            128 + self.lo.leading_zeros()
+        } else {
+            self.hi.leading_zeros()
+        }
+    }
+
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let (lo, carry) = self.lo.overflowing_add(rhs.lo);
+        let (hi, overflow0) = self.hi.overflowing_add(rhs.hi);
+        let (hi, overflow1) = hi.overflowing_add(carry as i128);
+        (Self::from_parts(lo, hi), overflow0 != overflow1)
+    }
+
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        self.overflowing_add(rhs).0
+    }
+
+    pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let (lo, borrow) = self.lo.overflowing_sub(rhs.lo);
+        let (hi, overflow0) = self.hi.overflowing_sub(rhs.hi);
+        let (hi, overflow1) = hi.overflowing_sub(borrow as i128);
+        (Self::from_parts(lo, hi), overflow0 != overflow1)
+    }
+
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+
+    pub const fn wrapping_neg(self) -> Self {
+        Self::from_parts(0, 0).wrapping_sub(self)
+    }
+
+    /// Returns the absolute value as the `(high, low)` halves of an unsigned
+    /// 256 bit integer
+    pub const fn unsigned_abs(self) -> (u128, u128) {
+        let x = if self.is_negative() {
+            self.wrapping_neg()
+        } else {
+            self
+        };
+        (x.hi as u128, x.lo)
+    }
+
+    /// Converts to an `awint` integer
+    pub fn to_awi(self) -> inlawi_ty!(256) {
+        let mut res = <inlawi_ty!(256)>::zero();
+        res.i128_(self.hi);
+        res.shl_(128).unwrap();
+        res.field_to(0, &InlAwi::from_u128(self.lo), 128).unwrap();
+        res
+    }
+
+    /// Converts from the least significant 256 bits of `x`, which should have
+    /// a bitwidth of at least 256
+    pub fn from_bits(x: &Bits) -> Self {
+        let mut hi = InlAwi::from_i128(0);
+        hi.field_from(x, 128, 128).unwrap();
+        Self::from_parts(x.to_u128(), hi.to_i128())
+    }
+
+    pub fn wrapping_mul(self, rhs: Self) -> Self {
+        let mut res = <inlawi_ty!(256)>::zero();
+        res.mul_add_(&self.to_awi(), &rhs.to_awi()).unwrap();
+        Self::from_bits(&res)
+    }
+
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        let mut res = <inlawi_ty!(512)>::zero();
+        res.arb_imul_add_(&mut self.to_awi(), &mut rhs.to_awi());
+        // the product fits if the bits above the sign bit are all the same
+        let mut high = <inlawi_ty!(257)>::zero();
+        high.field_from(&res, 255, 257).unwrap();
+        if high.is_zero() || high.is_umax() {
+            Self::from_bits(&res)
+        } else if self.is_negative() != rhs.is_negative() {
+            Self::MIN
+        } else {
+            Self::MAX
+        }
+    }
+
+    /// Returns the quotient and remainder truncated towards zero
+    ///
+    /// # Panics
+    ///
+    /// If `rhs` is zero
+    fn div_rem(self, rhs: Self) -> (Self, Self) {
+        let mut quo = <inlawi_ty!(256)>::zero();
+        let mut rem = <inlawi_ty!(256)>::zero();
+        if Bits::idivide(&mut quo, &mut rem, &mut self.to_awi(), &mut rhs.to_awi()).is_none() {
+            panic!("attempt to divide by zero")
+        }
+        (Self::from_bits(&quo), Self::from_bits(&rem))
+    }
+
+    pub fn wrapping_div(self, rhs: Self) -> Self {
+        self.div_rem(rhs).0
+    }
+
+    pub fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+        (
+            self.wrapping_div(rhs),
+            (self == Self::MIN) && (rhs == Self::from_i128(-1)),
+        )
+    }
+
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs == Self::from_i128(0) {
+            return None
+        }
+        match self.overflowing_div(rhs) {
+            (quo, false) => Some(quo),
+            (_, true) => None,
+        }
+    }
+
+    pub fn wrapping_rem(self, rhs: Self) -> Self {
+        self.div_rem(rhs).1
+    }
+
+    pub const fn to_le_bytes(self) -> [u8; 32] {
+        let mut res = [0; 32];
+        let lo = self.lo.to_le_bytes();
+        let hi = self.hi.to_le_bytes();
+        let mut i = 0;
+        while i < 16 {
+            res[i] = lo[i];
+            res[i + 16] = hi[i];
+            i += 1;
+        }
+        res
+    }
+
+    pub const fn from_le_bytes(bytes: [u8; 32]) -> Self {
+        let mut lo = [0; 16];
+        let mut hi = [0; 16];
+        let mut i = 0;
+        while i < 16 {
+            lo[i] = bytes[i];
+            hi[i] = bytes[i + 16];
+            i += 1;
+        }
+        Self::from_parts(u128::from_le_bytes(lo), i128::from_le_bytes(hi))
+    }
+}
+
+impl FracintInt for i256 {
+    type Frac = (u128, u128);
+
+    const NEG_ONE: Self = Self::from_i128(-1);
+    const ONE: Self = Self::from_i128(1);
+    const ZERO: Self = Self::from_i128(0);
+
+    fn is_odd(self) -> bool {
+        (self.lo & 1) != 0
+    }
+
+    fn abs_frac(self) -> (u128, u128) {
+        self.unsigned_abs()
+    }
+
+    fn magnitude(self) -> (u128, u128) {
+        self.unsigned_abs().shl_or(1, 0)
+    }
+
+    fn from_bits(x: &Bits) -> Self {
+        Self::from_bits(x)
+    }
+}
+
+impl fmt::Debug for i256 {
+    /// Writes the two's complement bits in hexadecimal
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "i256(0x{:032x}_{:032x})", self.hi, self.lo)
+    }
+}
+
+impl From<i128> for i256 {
+    /// Lossless conversion
+    fn from(x: i128) -> Self {
+        Self::from_i128(x)
+    }
+}
+
+impl From<u128> for i256 {
+    /// Lossless conversion
+    fn from(x: u128) -> Self {
+        Self::from_parts(x, 0)
+    }
+}
+
+impl TryFrom<i256> for u128 {
+    type Error = TryFromIntError;
+
+    fn try_from(x: i256) -> Result<Self, Self::Error> {
+        if x.hi == 0 {
+            Ok(x.lo)
+        } else {
+            // `TryFromIntError` can only be created by a failing conversion
+            Err(u128::try_from(-1i128).unwrap_err())
+        }
+    }
+}
+
+impl From<i256> for inlawi_ty!(256) {
+    /// Lossless conversion
+    fn from(x: i256) -> Self {
+        x.to_awi()
+    }
+}
+
+impl Shl<usize> for i256 {
+    type Output = Self;
+
+    fn shl(self, rhs: usize) -> Self {
+        assert!(rhs < 256, "attempt to shift left with overflow");
+        if rhs == 0 {
+            self
+        } else if rhs < 128 {
+            Self::from_parts(
+                self.lo << rhs,
+                (self.hi << rhs) | ((self.lo >> (128 - rhs)) as i128),
+            )
+        } else {
+            Self::from_parts(0, (self.lo << (rhs - 128)) as i128)
+        }
+    }
+}
+
+impl Shr<usize> for i256 {
+    type Output = Self;
+
+    /// Arithmetic right shift
+    fn shr(self, rhs: usize) -> Self {
+        assert!(rhs < 256, "attempt to shift right with overflow");
+        if rhs == 0 {
+            self
+        } else if rhs < 128 {
+            Self::from_parts(
+                (self.lo >> rhs) | ((self.hi as u128) << (128 - rhs)),
+                self.hi >> rhs,
+            )
+        } else {
+            Self::from_parts((self.hi >> (rhs - 128)) as u128, self.hi >> 127)
+        }
+    }
+}
+
+impl ShlAssign<usize> for i256 {
+    fn shl_assign(&mut self, rhs: usize) {
+        *self = *self << rhs;
+    }
+}
+
+impl ShrAssign<usize> for i256 {
+    fn shr_assign(&mut self, rhs: usize) {
+        *self = *self >> rhs;
+    }
+}
+
+impl Not for i256 {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self::from_parts(!self.lo, !self.hi)
+    }
+}
+
+impl BitOr for i256 {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self::from_parts(self.lo | rhs.lo, self.hi | rhs.hi)
+    }
+}
+
+impl BitAnd for i256 {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self::from_parts(self.lo & rhs.lo, self.hi & rhs.hi)
+    }
+}
+
+impl BitXor for i256 {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self {
+        Self::from_parts(self.lo ^ rhs.lo, self.hi ^ rhs.hi)
+    }
+}
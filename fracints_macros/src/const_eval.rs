@@ -0,0 +1,329 @@
+//! Evaluation of constant expressions for `fracint_const!`
+
+use std::sync::OnceLock;
+
+use fracints_internals::reference::{INT_BITS, Ref};
+use proc_macro::{Delimiter, Span, TokenStream, TokenTree};
+
+use crate::flatten;
+
+/// The number of fraction bits used for evaluation, which leaves 129 guard
+/// bits below those of `fi256`
+pub const FP: usize = 384;
+
+/// The error message and the span of the offending token
+pub type EvalError = (String, Span);
+
+fn err<T>(msg: &str, span: Span) -> Result<T, EvalError> {
+    Err((msg.to_owned(), span))
+}
+
+/// Returns an error if the magnitude is close enough to the integer bits of
+/// `Ref` that further operations could wrap around
+fn check(x: Ref, span: Span) -> Result<Ref, EvalError> {
+    if x.abs().awi.sig() > (FP + INT_BITS - 4) {
+        err("the value is too large to evaluate", span)
+    } else {
+        Ok(x)
+    }
+}
+
+fn int(x: i128) -> Ref {
+    Ref::from_i128(x, FP)
+}
+
+/// Returns `pi`, which is cached since it is used by many tables
+fn pi() -> Ref {
+    static PI: OnceLock<Ref> = OnceLock::new();
+    PI.get_or_init(|| Ref::pi(FP)).clone()
+}
+
+/// Parses an integer or float literal
+fn literal(s: &str, span: Span) -> Result<Ref, EvalError> {
+    let s = s.replace('_', "");
+    let unsupported = || {
+        err(
+            "expected an integer or float literal without a suffix",
+            span,
+        )
+    };
+    for (prefix, radix) in [("0b", 2), ("0o", 8), ("0x", 16)] {
+        if let Some(digits) = s.strip_prefix(prefix) {
+            return match i128::from_str_radix(digits, radix) {
+                Ok(x) => check(int(x), span),
+                Err(_) => unsupported(),
+            }
+        }
+    }
+    let (mantissa, exp) = match s.split_once(['e', 'E']) {
+        Some((mantissa, exp)) => match exp.parse::<i32>() {
+            Ok(exp) => (mantissa, exp),
+            Err(_) => return unsupported(),
+        },
+        None => (s.as_str(), 0),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if integer.is_empty() || !(integer.bytes().chain(fraction.bytes())).all(|c| c.is_ascii_digit())
+    {
+        return unsupported()
+    }
+    let ten = int(10);
+    let mut x = int(0);
+    for c in integer.bytes() {
+        x = check(x.mul(&ten).add(&int((c - b'0') as i128)), span)?;
+    }
+    // the fraction is accumulated from the least significant digit
+    let mut frac = int(0);
+    for c in fraction.bytes().rev() {
+        frac = frac.add(&int((c - b'0') as i128)).div_int(10);
+    }
+    x = x.add(&frac);
+    for _ in 0..exp.max(0) {
+        x = check(x.mul(&ten), span)?;
+    }
+    for _ in exp.min(0)..0 {
+        if x.is_zero() {
+            break
+        }
+        x = x.div_int(10);
+    }
+    Ok(x)
+}
+
+/// A recursive descent parser and evaluator
+struct Parser<'a> {
+    tokens: Vec<TokenTree>,
+    i: usize,
+    /// The span used for errors at the end of the input
+    end: Span,
+    /// The value of the variable `x` if there is one
+    x: Option<&'a Ref>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: TokenStream, end: Span, x: Option<&'a Ref>) -> Self {
+        let mut tokens = vec![];
+        flatten(input, &mut tokens);
+        Self {
+            tokens,
+            i: 0,
+            end,
+            x,
+        }
+    }
+
+    fn peek_punct(&self) -> Option<char> {
+        match self.tokens.get(self.i) {
+            Some(TokenTree::Punct(p)) => Some(p.as_char()),
+            _ => None,
+        }
+    }
+
+    fn finish(&self) -> Result<(), EvalError> {
+        match self.tokens.get(self.i) {
+            Some(token) => err("unexpected token", token.span()),
+            None => Ok(()),
+        }
+    }
+
+    /// expr := term (('+' | '-') term)*
+    fn expr(&mut self) -> Result<Ref, EvalError> {
+        let mut x = self.term()?;
+        while let Some(c @ ('+' | '-')) = self.peek_punct() {
+            let span = self.tokens[self.i].span();
+            self.i += 1;
+            let y = self.term()?;
+            x = check(if c == '+' { x.add(&y) } else { x.sub(&y) }, span)?;
+        }
+        Ok(x)
+    }
+
+    /// term := unary (('*' | '/') unary)*
+    fn term(&mut self) -> Result<Ref, EvalError> {
+        let mut x = self.unary()?;
+        while let Some(c @ ('*' | '/')) = self.peek_punct() {
+            let span = self.tokens[self.i].span();
+            self.i += 1;
+            let y = self.unary()?;
+            let (sig_x, sig_y) = (x.abs().awi.sig(), y.abs().awi.sig());
+            x = if c == '*' {
+                if (sig_x + sig_y) > (2 * FP + INT_BITS - 4) {
+                    return err("the value is too large to evaluate", span)
+                }
+                x.mul(&y)
+            } else {
+                if y.is_zero() {
+                    return err("division by zero", span)
+                }
+                if (sig_x + FP) > (sig_y + FP + INT_BITS - 4) {
+                    return err("the value is too large to evaluate", span)
+                }
+                x.div(&y)
+            };
+        }
+        Ok(x)
+    }
+
+    /// unary := '-' unary | primary
+    fn unary(&mut self) -> Result<Ref, EvalError> {
+        if self.peek_punct() == Some('-') {
+            self.i += 1;
+            Ok(self.unary()?.neg())
+        } else {
+            self.primary()
+        }
+    }
+
+    /// primary := literal | constant | function '(' expr (',' expr)* ')' | '('
+    /// expr ')'
+    fn primary(&mut self) -> Result<Ref, EvalError> {
+        let Some(token) = self.tokens.get(self.i).cloned() else {
+            return err("expected an expression", self.end)
+        };
+        self.i += 1;
+        let span = token.span();
+        match token {
+            TokenTree::Literal(lit) => literal(&lit.to_string(), span),
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis => {
+                let mut parser = Parser::new(group.stream(), group.span_close(), self.x);
+                let x = parser.expr()?;
+                parser.finish()?;
+                Ok(x)
+            }
+            TokenTree::Ident(ident) => {
+                let name = ident.to_string();
+                match (name.as_str(), self.x) {
+                    ("pi", _) => return Ok(pi()),
+                    ("tau", _) => return Ok(int(2).mul(&pi())),
+                    ("e", _) => return Ok(int(1).exp()),
+                    ("x", Some(x)) => return Ok(x.clone()),
+                    _ => (),
+                }
+                let args = match self.tokens.get(self.i) {
+                    Some(TokenTree::Group(group))
+                        if group.delimiter() == Delimiter::Parenthesis =>
+                    {
+                        self.i += 1;
+                        self.args(group.stream(), group.span_close())?
+                    }
+                    _ => return err("unknown constant", span),
+                };
+                function(&name, &args, span)
+            }
+            _ => err("expected an expression", span),
+        }
+    }
+
+    /// Parses comma separated arguments
+    fn args(&self, input: TokenStream, end: Span) -> Result<Vec<Ref>, EvalError> {
+        let mut parser = Parser::new(input, end, self.x);
+        let mut args = vec![parser.expr()?];
+        while parser.peek_punct() == Some(',') {
+            parser.i += 1;
+            args.push(parser.expr()?);
+        }
+        parser.finish()?;
+        Ok(args)
+    }
+}
+
+/// Reduces an angle in radians to the range `[0, tau)`
+fn reduce_angle(x: &Ref) -> Ref {
+    let tau = int(2).mul(&pi());
+    let mut k = x.div(&tau);
+    // floor to an integer
+    k.awi.ashr_(FP).unwrap();
+    k.awi.shl_(FP).unwrap();
+    x.sub(&k.mul(&tau))
+}
+
+fn function(name: &str, args: &[Ref], span: Span) -> Result<Ref, EvalError> {
+    let arity = match name {
+        "atan2" => 2,
+        _ if is_function(name) => 1,
+        _ => return err("unknown function", span),
+    };
+    if args.len() != arity {
+        return err(&format!("`{name}` takes {arity} argument(s)"), span)
+    }
+    let x = &args[0];
+    let one = int(1);
+    let res = match name {
+        "sqrt" => {
+            if x.is_negative() {
+                return err("`sqrt` of a negative value", span)
+            }
+            x.sqrt()
+        }
+        "sin" => reduce_angle(x).cos_sin().1,
+        "cos" => reduce_angle(x).cos_sin().0,
+        "tan" => {
+            let (cos, sin) = reduce_angle(x).cos_sin();
+            if cos.is_zero() {
+                return err("`tan` is undefined", span)
+            }
+            check(sin.div(&cos), span)?
+        }
+        "atan" => x.atan(),
+        "atan2" => Ref::atan2(x, &args[1], &pi()),
+        "asin" | "acos" => {
+            if x.abs().awi.igt(&one.awi).unwrap() {
+                return err(&format!("`{name}` of a value outside of `[-1, 1]`"), span)
+            }
+            let y = one.sub(&x.mul(x)).sqrt();
+            if name == "asin" {
+                Ref::atan2(x, &y, &pi())
+            } else {
+                Ref::atan2(&y, x, &pi())
+            }
+        }
+        "exp" => {
+            // `e^40 < 2^58`
+            if x.awi.igt(&int(40).awi).unwrap() {
+                return err("`exp` of a value that is too large", span)
+            }
+            x.exp()
+        }
+        "ln" | "log2" | "log10" => {
+            if x.is_negative() || x.is_zero() {
+                return err(&format!("`{name}` of a nonpositive value"), span)
+            }
+            match name {
+                "ln" => x.ln(),
+                "log2" => x.log2(),
+                _ => x.ln().div(&int(10).ln()),
+            }
+        }
+        _ => x.abs(),
+    };
+    Ok(res)
+}
+
+/// Returns if `name` is a function that `eval` supports
+pub fn is_function(name: &str) -> bool {
+    matches!(
+        name,
+        "sqrt"
+            | "sin"
+            | "cos"
+            | "tan"
+            | "atan"
+            | "atan2"
+            | "asin"
+            | "acos"
+            | "exp"
+            | "ln"
+            | "log2"
+            | "log10"
+            | "abs"
+    )
+}
+
+/// Evaluates the expression in `input`, which can use the variable `x` if it
+/// is given
+pub fn eval(input: TokenStream, end: Span, x: Option<&Ref>) -> Result<Ref, EvalError> {
+    let mut parser = Parser::new(input, end, x);
+    let x = parser.expr()?;
+    parser.finish()?;
+    Ok(x)
+}
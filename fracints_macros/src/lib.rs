@@ -1,34 +1,379 @@
 extern crate proc_macro;
-use fracints_internals::*;
-use proc_macro::TokenStream;
+mod const_eval;
+
+use std::fmt;
+
+use fracints_internals::{reference::Ref, *};
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// Returns a `::core::compile_error!(msg)` invocation with all tokens at `span`
+fn compile_error(msg: &str, span: Span) -> TokenStream {
+    let mut lit = Literal::string(msg);
+    lit.set_span(span);
+    let mut group = Group::new(Delimiter::Parenthesis, TokenTree::from(lit).into());
+    group.set_span(span);
+    let punct = |c, spacing| {
+        let mut p = Punct::new(c, spacing);
+        p.set_span(span);
+        TokenTree::from(p)
+    };
+    [
+        punct(':', Spacing::Joint),
+        punct(':', Spacing::Alone),
+        TokenTree::from(Ident::new("core", span)),
+        punct(':', Spacing::Joint),
+        punct(':', Spacing::Alone),
+        TokenTree::from(Ident::new("compile_error", span)),
+        punct('!', Spacing::Alone),
+        TokenTree::from(group),
+    ]
+    .into_iter()
+    .collect()
+}
+
+/// Returns the contents of a plain or raw string literal, or `None` if the
+/// literal is not a string
+fn string_literal_contents(lit: &str) -> Option<Result<&str, &'static str>> {
+    let raw = lit.strip_prefix('r').map(|s| s.trim_matches('#'));
+    if let Some(s) = raw.unwrap_or(lit).strip_prefix('"') {
+        let s = s.strip_suffix('"').unwrap();
+        if raw.is_none() && s.contains('\\') {
+            return Some(Err("escapes are not supported in fracint string literals"))
+        }
+        Some(Ok(s))
+    } else {
+        None
+    }
+}
+
+/// Flattens the tokens of the input, which can be wrapped by `macro_rules`
+/// fragments
+fn flatten(input: TokenStream, tokens: &mut Vec<TokenTree>) {
+    for token in input {
+        match token {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::None => {
+                flatten(group.stream(), tokens)
+            }
+            _ => tokens.push(token),
+        }
+    }
+}
+
+/// Converts the tokens into the string to parse and the span of each token,
+/// with the byte offset in the string where each token ends
+fn tokens_to_string(tokens: &[TokenTree]) -> Result<(String, Vec<(usize, Span)>), TokenStream> {
+    let mut s = String::new();
+    let mut spans = vec![];
+    for token in tokens {
+        if let TokenTree::Literal(lit) = token
+            && let Some(contents) = string_literal_contents(&lit.to_string())
+        {
+            if tokens.len() != 1 {
+                return Err(compile_error(
+                    "a string literal should be the only input",
+                    token.span(),
+                ))
+            }
+            s.push_str(contents.map_err(|e| compile_error(e, token.span()))?);
+        } else {
+            // we can't get an exact char-for-char copy but this gets close enough
+            s.push_str(&token.to_string());
+        }
+        spans.push((s.len(), token.span()));
+    }
+    Ok((s, spans))
+}
+
+/// Splits the tokens at top level commas, ignoring a trailing comma
+fn split_commas(tokens: &[TokenTree]) -> Vec<&[TokenTree]> {
+    let mut res: Vec<_> = tokens
+        .split(|t| matches!(t, TokenTree::Punct(p) if p.as_char() == ','))
+        .collect();
+    if res.last().is_some_and(|last| last.is_empty()) {
+        res.pop();
+    }
+    res
+}
+
+/// Parses the tokens of a literal into `ty(x)`, or returns a `compile_error!`
+/// at the offending token
+fn literal<I: fmt::Display>(
+    ty: &str,
+    from_str: fn(&str) -> Result<I, FracintParseError>,
+    tokens: &[TokenTree],
+) -> TokenStream {
+    let (s, spans) = match tokens_to_string(tokens) {
+        Ok(x) => x,
+        Err(e) => return e,
+    };
+    match from_str(&s) {
+        Ok(x) => format!("{ty}({x})").parse().unwrap(),
+        Err(e) => {
+            let span = spans
+                .iter()
+                .find(|(end, _)| e.position() < *end)
+                .or(spans.last())
+                .map_or_else(Span::call_site, |(_, span)| *span);
+            compile_error(&format!("invalid `{ty}` string representation: {e}"), span)
+        }
+    }
+}
+
+/// Parses comma separated literals into an array, with a `compile_error!` for
+/// each invalid element
+fn literal_array<I: fmt::Display>(
+    ty: &str,
+    from_str: fn(&str) -> Result<I, FracintParseError>,
+    input: TokenStream,
+) -> TokenStream {
+    let mut tokens = vec![];
+    flatten(input, &mut tokens);
+    let mut elements = TokenStream::new();
+    for element in split_commas(&tokens) {
+        elements.extend(literal(ty, from_str, element));
+        elements.extend([TokenTree::from(Punct::new(',', Spacing::Alone))]);
+    }
+    TokenTree::from(Group::new(Delimiter::Bracket, elements)).into()
+}
+
+/// Writes an `i256` as a constant expression, because it has no literals
+struct I256Expr(i256);
+
+impl fmt::Display for I256Expr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "i256::from_parts({}, {})", self.0.lo(), self.0.hi())
+    }
+}
+
+fn i256_expr_from_str(s: &str) -> Result<I256Expr, FracintParseError> {
+    i256_from_str(s).map(I256Expr)
+}
 
 macro_rules! impl_fracint_macros {
-($($ty:ident $from_str: ident);*;) => {$(
+($($ty:ident $tys:ident $from_str:ident);*;) => {$(
     /// Literal conversion into the type. See `fiN::from_str` for the definition.
+    ///
+    /// The input can be tokens that are close to a Rust literal such as
+    /// `fi32!(-0.123e-4)`, or a string literal such as `fi32!("0x0.8p-1")`
+    /// for forms that Rust cannot tokenize. Invalid input results in a
+    /// `compile_error!` at the offending token.
     #[proc_macro]
     pub fn $ty(input: TokenStream) -> TokenStream {
-        // we can't get an exact char-for-char copy but this gets close enough
-        let mut s = String::new();
-        for token in input {
-            s.push_str(&token.to_string())
-        }
-        for c in s.chars() {
-            if c == '"' {
-                panic!("The literal input to this macro should not include quotation marks");
-            }
-        }
-        match $from_str(&s) {
-            Ok(x) => format!("{}({})", stringify!($ty), x).parse().unwrap(),
-            Err(e) => panic!("Invalid `{}` string representation: {}", stringify!($ty), e,),
-        }
+        let mut tokens = vec![];
+        flatten(input, &mut tokens);
+        literal(stringify!($ty), $from_str, &tokens)
+    }
+
+    #[doc = concat!("An array of `", stringify!($ty), "` literals such as `", stringify!($tys),
+        "![0.1, -0.25, 1e-3]`")]
+    ///
+    /// Each element is parsed at compile time the same way as by the single
+    /// literal macro, and each invalid element results in its own
+    /// `compile_error!`.
+    #[proc_macro]
+    pub fn $tys(input: TokenStream) -> TokenStream {
+        literal_array(stringify!($ty), $from_str, input)
     }
 )*}
 }
 
 impl_fracint_macros!(
-    fi8 i8_from_str;
-    fi16 i16_from_str;
-    fi32 i32_from_str;
-    fi64 i64_from_str;
-    fi128 i128_from_str;
+    fi8 fi8s i8_from_str;
+    fi16 fi16s i16_from_str;
+    fi32 fi32s i32_from_str;
+    fi64 fi64s i64_from_str;
+    fi128 fi128s i128_from_str;
+    fi256 fi256s i256_expr_from_str;
+    fu8 fu8s u8_from_str;
+    fu16 fu16s u16_from_str;
+    fu32 fu32s u32_from_str;
+    fu64 fu64s u64_from_str;
+    fu128 fu128s u128_from_str;
 );
+
+/// The name, signedness, and bitwidth of a fracint type
+struct FracintType {
+    ident: Ident,
+    signed: bool,
+    bits: usize,
+}
+
+impl FracintType {
+    /// Parses the type and following comma at the start of `tokens`
+    fn parse(tokens: &[TokenTree]) -> Result<Self, TokenStream> {
+        let ident = match tokens.first() {
+            Some(TokenTree::Ident(ident)) => ident.clone(),
+            Some(token) => return Err(compile_error("expected a fracint type", token.span())),
+            None => return Err(compile_error("expected a fracint type", Span::call_site())),
+        };
+        let s = ident.to_string();
+        let signed = s.starts_with("fi");
+        let bits: usize = match s.get(2..).map(str::parse) {
+            Some(Ok(bits)) if signed || s.starts_with("fu") => bits,
+            _ => 0,
+        };
+        if ![8, 16, 32, 64, 128].contains(&bits) && (s != "fi256") {
+            return Err(compile_error("expected a fracint type", ident.span()))
+        }
+        match tokens.get(1) {
+            Some(TokenTree::Punct(p)) if p.as_char() == ',' => (),
+            Some(token) => return Err(compile_error("expected a ','", token.span())),
+            None => return Err(compile_error("expected a ','", Span::call_site())),
+        }
+        Ok(Self {
+            ident,
+            signed,
+            bits,
+        })
+    }
+
+    /// Rounds `x` to even to the nearest value of the type and returns the
+    /// integer expression, or returns an error message if `x` is out of range
+    fn int_literal(&self, x: &Ref) -> Result<String, String> {
+        let fp = self.bits - (self.signed as usize);
+        let overflow = |negative| {
+            Err(format!(
+                "invalid `{}` constant: {}",
+                self.ident,
+                FracintParseError::new(FracintSerdeError::Overflow, 0, negative)
+            ))
+        };
+        let (negative, (hi, lo)) = match x.to_fraction::<(u128, u128)>(fp) {
+            Err(_) => return overflow(x.is_negative()),
+            // `ONE` and `NEG_ONE` special cases
+            Ok((negative, None)) => (negative, FractionBits::mask(fp)),
+            // negative zero is valid for unsigned types
+            Ok((_, Some((0, 0)))) => (false, (0, 0)),
+            Ok((negative, Some(mag))) => (negative, mag),
+        };
+        if negative && !self.signed {
+            return overflow(true)
+        }
+        if self.bits == 256 {
+            let mag = i256::from_parts(lo, hi as i128);
+            Ok(I256Expr(if negative { mag.wrapping_neg() } else { mag }).to_string())
+        } else if negative {
+            Ok(format!("-{lo}"))
+        } else {
+            Ok(format!("{lo}"))
+        }
+    }
+}
+
+/// Evaluates a constant expression with high precision and rounds it to even
+/// to the nearest value of the fracint type, which is given as the first
+/// argument.
+///
+/// ```
+/// use fracints::prelude::*;
+///
+/// assert_eq!(
+///     fracint_const!(fi32, sqrt(0.5)),
+///     fi32!(0.7071067811865475244)
+/// );
+/// assert_eq!(fracint_const!(fi64, 1 / 3), fi64!(0.333333333333333333333));
+/// assert_eq!(fracint_const!(fu16, pi / 4), fu16!(0.7853981633974483));
+/// assert_eq!(fracint_const!(fi16, -cos(0.1)), fi16!(-0.9950041652780258));
+/// assert_eq!(fracint_const!(fi256, 1 / 4), fi256!(0.25));
+/// ```
+///
+/// The expression can use integer and float literals, `+`, `-`, `*`, `/`,
+/// parentheses, the constants `pi`, `tau`, and `e`, and the functions `sqrt`,
+/// `sin`, `cos`, `tan`, `atan`, `atan2`, `asin`, `acos`, `exp`, `ln`, `log2`,
+/// `log10`, and `abs`. Angles are in radians. The expression is evaluated with
+/// 384 fraction bits, so the result is correctly rounded unless the exact value
+/// is extremely close to halfway between two fracints. Invalid expressions and
+/// values outside of the range of the type result in a `compile_error!`. Like
+/// the literal macros, values that round to 1 or -1 become `ONE` or `NEG_ONE`.
+#[proc_macro]
+pub fn fracint_const(input: TokenStream) -> TokenStream {
+    let mut tokens = vec![];
+    flatten(input, &mut tokens);
+    let ty = match FracintType::parse(&tokens) {
+        Ok(ty) => ty,
+        Err(e) => return e,
+    };
+    let expr = tokens[2..].iter().cloned().collect();
+    let x = match const_eval::eval(expr, Span::call_site(), None) {
+        Ok(x) => x,
+        Err((msg, span)) => return compile_error(&msg, span),
+    };
+    match ty.int_literal(&x) {
+        Ok(int) => format!("{}({int})", ty.ident).parse().unwrap(),
+        Err(msg) => compile_error(&msg, Span::call_site()),
+    }
+}
+
+/// Generates a lookup table at compile time, as an array of `n` fracints of
+/// the type given by the first argument. Element `i` is the function evaluated
+/// at `x = i / n`, and is rounded the same way as by [fracint_const!].
+///
+/// The function can be the name of one of the single argument functions that
+/// [fracint_const!] supports, or an expression of `x`.
+///
+/// ```
+/// use fracints::prelude::*;
+///
+/// const SQRT: [fu16; 64] = fracint_lut!(fu16, sqrt, 64);
+/// assert_eq!(SQRT[16], fu16!(0.5));
+/// // one period
+/// const SIN: [fi16; 256] = fracint_lut!(fi16, sin(tau * x), 256);
+/// assert_eq!(SIN[32], fracint_const!(fi16, sqrt(0.5)));
+/// assert_eq!(SIN[192], fi16::NEG_ONE);
+/// ```
+#[proc_macro]
+pub fn fracint_lut(input: TokenStream) -> TokenStream {
+    let mut tokens = vec![];
+    flatten(input, &mut tokens);
+    let ty = match FracintType::parse(&tokens) {
+        Ok(ty) => ty,
+        Err(e) => return e,
+    };
+    let args = split_commas(&tokens[2..]);
+    let [f, n] = args[..] else {
+        return compile_error("expected a function and a table length", Span::call_site())
+    };
+    let f: TokenStream = match f {
+        [TokenTree::Ident(ident)] if const_eval::is_function(&ident.to_string()) => {
+            let x = TokenTree::from(Ident::new("x", ident.span()));
+            [
+                f[0].clone(),
+                TokenTree::from(Group::new(Delimiter::Parenthesis, x.into())),
+            ]
+            .into_iter()
+            .collect()
+        }
+        _ => f.iter().cloned().collect(),
+    };
+    let n_span = n.first().map_or_else(Span::call_site, |t| t.span());
+    let len = match n {
+        [TokenTree::Literal(lit)] => lit.to_string().replace('_', "").parse::<i128>().ok(),
+        _ => None,
+    };
+    // larger tables would take a very long time to evaluate
+    let Some(len) = len.filter(|len| (1..=(1 << 16)).contains(len)) else {
+        return compile_error("expected a table length in `1..=65536`", n_span)
+    };
+    let mut elements = TokenStream::new();
+    for i in 0..len {
+        let x = Ref::from_i128(i, const_eval::FP).div_int(len as usize);
+        let y = match const_eval::eval(f.clone(), Span::call_site(), Some(&x)) {
+            Ok(y) => y,
+            Err((msg, span)) => {
+                return compile_error(&format!("{msg} (at `x = {i} / {len}`)"), span)
+            }
+        };
+        let int = match ty.int_literal(&y) {
+            Ok(int) => int,
+            Err(msg) => {
+                return compile_error(&format!("{msg} (at `x = {i} / {len}`)"), Span::call_site())
+            }
+        };
+        elements.extend(
+            format!("{}({int}),", ty.ident)
+                .parse::<TokenStream>()
+                .unwrap(),
+        );
+    }
+    TokenTree::from(Group::new(Delimiter::Bracket, elements)).into()
+}
@@ -1,6 +1,8 @@
 #![feature(test)]
 
 extern crate test;
+use std::str::FromStr;
+
 use fracints::prelude::*;
 use star_rng::StarRng;
 use test::Bencher;
@@ -40,3 +42,13 @@ fn fi64_widen_sqr(bencher: &mut Bencher) {
         x.saturating_widening_mul(x)
     })
 }
+
+#[bench]
+fn fi64_from_str(bencher: &mut Bencher) {
+    bencher.iter(|| fi64::from_str(test::black_box("-0.123_456_789_012_345")))
+}
+
+#[bench]
+fn fi128_from_str(bencher: &mut Bencher) {
+    bencher.iter(|| fi128::from_str(test::black_box("0.636619772367581343075535053490057448137")))
+}
@@ -5,23 +5,186 @@
 
 use std::fmt::Write;
 
-use common::sqrt::simple_isqrt_lut;
-use fracints::{Fracint, fi16};
+use common::{reference::Ref, sqrt::simple_isqrt_lut};
+use fracints::prelude::*;
+
+/// Formats the integer of a fracint as a constant expression
+trait IntExpr {
+    fn int_expr(self) -> String;
+}
+
+macro_rules! impl_int_expr {
+    ($($ty:ident)*) => {$(
+        impl IntExpr for $ty {
+            fn int_expr(self) -> String {
+                format!("{:?}", self.as_int())
+            }
+        }
+    )*};
+}
+
+impl_int_expr!(fi16 fi32 fi64 fi128);
+
+impl IntExpr for fi256 {
+    fn int_expr(self) -> String {
+        // `i256` has no literals
+        format!("i256::from_parts({}, {})", self.0.lo(), self.0.hi())
+    }
+}
+
+/// Returns the number of Taylor series terms after the first that are nonzero
+/// at the largest `theta_sqr` used by `cos_taudiv4_taylor_base` and
+/// `sin_taudiv4_taylor_base`
+fn taylor_iters<F: Fracint>(theta_sqr: F, mut term: F, mut factorial_num: usize) -> usize {
+    let mut iters = 0;
+    loop {
+        let div = F::Int::try_from(((factorial_num + 1) * (factorial_num + 2)) as u128).unwrap();
+        term = term.wrapping_mul(theta_sqr).saturating_div_int(div);
+        factorial_num += 2;
+        if term.is_zero() {
+            break iters
+        }
+        iters += 1;
+    }
+}
+
+/// Returns the integers for `num_4divtau` and `num_4divtau_sqr`, and returns
+/// `cos_taylor_iters` and `sin_taylor_iters` for the extended precision type
+/// `F`
+fn taylor_constants<F: Fracint + IntExpr>() -> (String, String, usize, usize) {
+    let fp = 2 * F::BITS;
+    let num_4divtau = Ref::from_i128(2, fp).div(&Ref::pi(fp));
+    let num_4divtau_sqr = num_4divtau.mul(&num_4divtau).to_fracint::<F>();
+    let num_4divtau = num_4divtau.to_fracint::<F>();
+    // the base functions are called with at most 0.5
+    let t = F::ULP << (F::BITS - 2);
+    let theta_sqr = t.wrapping_mul(t).saturating_div(num_4divtau_sqr);
+    let theta = t.saturating_div(num_4divtau);
+    let cos_taylor_iters = taylor_iters(theta_sqr, theta_sqr >> 1, 2);
+    let sin_taylor_iters = taylor_iters(theta_sqr, theta, 1);
+    (
+        num_4divtau.int_expr(),
+        num_4divtau_sqr.int_expr(),
+        cos_taylor_iters,
+        sin_taylor_iters,
+    )
+}
+
+/// Returns `exp_taylor_iters` and `ln_taylor_iters` for the extended precision
+/// type `F`
+fn exp_ln_iters<F: Fracint>() -> (usize, usize) {
+    // the largest `x` used by `exp_neg_taylor_base` is one
+    let x = F::ONE;
+    let mut term = x;
+    let mut exp_taylor_iters = 0;
+    loop {
+        let div = F::Int::try_from((exp_taylor_iters + 2) as u128).unwrap();
+        term = term.wrapping_mul(x).saturating_div_int(div);
+        if term.is_zero() {
+            break
+        }
+        exp_taylor_iters += 1;
+    }
+    // `ln_1p_div2_taylor_base` saturates if `w` is less than -1/2
+    let w = F::NEG_ONE >> 1;
+    let w_sqr = w.wrapping_mul(w);
+    let mut pow = w;
+    let mut ln_taylor_iters = 0;
+    loop {
+        pow = pow.wrapping_mul(w_sqr);
+        let div = F::Int::try_from((2 * ln_taylor_iters + 3) as u128).unwrap();
+        if pow.saturating_div_int(div).is_zero() {
+            break
+        }
+        ln_taylor_iters += 1;
+    }
+    (exp_taylor_iters, ln_taylor_iters)
+}
+
+/// Returns the integers for the arctangent table and for the reciprocal of
+/// the gain after `iters` iterations of CORDIC, for the extended precision type
+/// `F`
+fn cordic_constants<F: Fracint + IntExpr>(iters: usize) -> (Vec<String>, String) {
+    let fp = 2 * F::BITS + 64;
+    let one = Ref::from_i128(1, fp);
+    let pi = Ref::pi(fp);
+    let mut atan_table = vec![];
+    let mut gain = one.clone();
+    for i in 0..iters {
+        // 2^-i
+        let mut pow = one.clone();
+        pow.awi.ashr_(i).unwrap();
+        atan_table.push(pow.atan().div(&pi).to_fracint::<F>().int_expr());
+        // the gain of each iteration is `sqrt(1 + 2^(-2*i))`
+        gain = gain.mul(&one.add(&pow.mul(&pow)).sqrt());
+    }
+    let gain = one.div(&gain);
+    (atan_table, gain.to_fracint::<F>().int_expr())
+}
+
+/// Returns the integers for the table of `log2(1 + 2^-i)` for `i` in
+/// `1..=iters`, in the extended precision type `F`
+fn log2_constants<F: Fracint + IntExpr>(iters: usize) -> Vec<String> {
+    let fp = 2 * F::BITS + 64;
+    let one = Ref::from_i128(1, fp);
+    let mut table = vec![];
+    for i in 1..=iters {
+        let mut pow = one.clone();
+        pow.awi.ashr_(i).unwrap();
+        table.push(one.add(&pow).log2().to_fracint::<F>().int_expr());
+    }
+    table
+}
 
 pub fn main() {
     let mut s = r#"// The generating function is in the testcrate of the repo containing this
 // crate.
+use fracints_internals::i256;
+
 use crate::impl_signed::*;
 
 "#
     .to_owned();
-    for w in [8usize, 16, 32, 64, 128] {
-        let fi = format!("fi{w}");
-        // FIXME
-        let num_4divtau = 81;
-        let num_4divtau_sqr = 52;
-        let cos_taylor_iters = 0;
-        let sin_taylor_iters = 0;
+    // The Taylor series are evaluated in the double width type and then rounded
+    let taylor = [
+        (
+            8,
+            "fi16",
+            taylor_constants::<fi16>(),
+            exp_ln_iters::<fi16>(),
+        ),
+        (
+            16,
+            "fi32",
+            taylor_constants::<fi32>(),
+            exp_ln_iters::<fi32>(),
+        ),
+        (
+            32,
+            "fi64",
+            taylor_constants::<fi64>(),
+            exp_ln_iters::<fi64>(),
+        ),
+        (
+            64,
+            "fi128",
+            taylor_constants::<fi128>(),
+            exp_ln_iters::<fi128>(),
+        ),
+        (
+            128,
+            "fi256",
+            taylor_constants::<fi256>(),
+            exp_ln_iters::<fi256>(),
+        ),
+    ];
+    for (
+        w,
+        fi,
+        (num_4divtau, num_4divtau_sqr, cos_taylor_iters, sin_taylor_iters),
+        (exp_taylor_iters, ln_taylor_iters),
+    ) in taylor
+    {
         writeln!(
             s,
             r#"pub struct Const{w} {{
@@ -29,6 +192,8 @@ use crate::impl_signed::*;
     pub num_4divtau_sqr: {fi},
     pub cos_taylor_iters: usize,
     pub sin_taylor_iters: usize,
+    pub exp_taylor_iters: usize,
+    pub ln_taylor_iters: usize,
 }}
 
 pub const CONST{w}: Const{w} = Const{w} {{
@@ -36,12 +201,71 @@ pub const CONST{w}: Const{w} = Const{w} {{
     num_4divtau_sqr: {fi}({num_4divtau_sqr}),
     cos_taylor_iters: {cos_taylor_iters},
     sin_taylor_iters: {sin_taylor_iters},
+    exp_taylor_iters: {exp_taylor_iters},
+    ln_taylor_iters: {ln_taylor_iters},
 }};
 "#
         )
         .unwrap()
     }
 
+    // CORDIC tables, using a few more iterations than bits in the result
+    let cordic = [
+        (8, "fi16", cordic_constants::<fi16>(10)),
+        (16, "fi32", cordic_constants::<fi32>(18)),
+        (32, "fi64", cordic_constants::<fi64>(34)),
+        (64, "fi128", cordic_constants::<fi128>(66)),
+        (128, "fi256", cordic_constants::<fi256>(130)),
+    ];
+    for (w, fi, (atan_table, gain)) in cordic {
+        let n = atan_table.len();
+        writeln!(s, r#"pub const CORDIC_ATAN{w}: [{fi}; {n}] = ["#).unwrap();
+        for entry in atan_table {
+            writeln!(s, r#"    {fi}({entry}),"#).unwrap();
+        }
+        writeln!(s, r#"];"#).unwrap();
+        writeln!(
+            s,
+            r#"pub const CORDIC_GAIN{w}: {fi} = {fi}({gain});
+"#
+        )
+        .unwrap();
+    }
+
+    // tables for `exp2_neg` and `log2`
+    let log2 = [
+        (8, "fi16", log2_constants::<fi16>(10)),
+        (16, "fi32", log2_constants::<fi32>(18)),
+        (32, "fi64", log2_constants::<fi64>(34)),
+        (64, "fi128", log2_constants::<fi128>(66)),
+        (128, "fi256", log2_constants::<fi256>(130)),
+    ];
+    for (w, fi, table) in log2 {
+        let n = table.len();
+        writeln!(s, r#"pub const LOG2_TABLE{w}: [{fi}; {n}] = ["#).unwrap();
+        for entry in table {
+            writeln!(s, r#"    {fi}({entry}),"#).unwrap();
+        }
+        writeln!(
+            s,
+            r#"];
+"#
+        )
+        .unwrap();
+    }
+
+    // full truncated square root table for the nonnegative `fi8` values
+    writeln!(s, r#"pub const SQRT_LUT8: [fi8; 128] = ["#).unwrap();
+    for i in 0..=i8::MAX {
+        writeln!(s, r#"    fi8({}),"#, fi8(i).sqrt_slow().as_int()).unwrap();
+    }
+    writeln!(
+        s,
+        r#"];
+"#
+    )
+    .unwrap();
+
     // isqrt table
     let n = 24;
     // find by setting to 1.0 and using the x value
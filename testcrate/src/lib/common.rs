@@ -1,6 +1,55 @@
+use core::fmt;
+
 mod optimize;
 mod optimizeables;
 pub mod sqrt;
+pub mod str_reference;
 
+use fracints::Fracint;
+pub use fracints_internals::reference;
 pub use optimize::*;
 pub use optimizeables::*;
+use reference::Ref;
+
+/// Panics if `actual` is more than `ulps` ULPs away from the unrounded
+/// `expected` value. `expected` is first saturated to the range between
+/// `F::NEG_ONE` (or `F::ZERO` if unsigned) and `F::ONE`, since those stand for
+/// negative one and one. `context` is printed on failure.
+#[track_caller]
+pub fn assert_within_ulps<F: Fracint>(
+    expected: &Ref,
+    actual: F,
+    ulps: f64,
+    context: fmt::Arguments,
+) {
+    let fp = expected.fp;
+    let min = Ref::from_fracint(if F::SIGNED { F::NEG_ONE } else { F::ZERO }, fp);
+    let max = Ref::from_fracint(F::ONE, fp);
+    let saturated = if expected.sub(&min).is_negative() {
+        &min
+    } else if max.sub(expected).is_negative() {
+        &max
+    } else {
+        expected
+    };
+    let diff = saturated.sub(&Ref::from_fracint(actual, fp)).abs();
+    // `ulps` in units of 2^-16 ULPs
+    let mut bound = Ref::from_i128((ulps * 65536.0) as i128, fp);
+    let frac_bits = F::BITS - (F::SIGNED as usize);
+    bound.awi.lshr_(16 + frac_bits).unwrap();
+    if bound.sub(&diff).is_negative() {
+        // the error in hundredths of an ULP
+        let mut error = diff
+            .div(&Ref::from_fracint(F::ULP, fp))
+            .mul(&Ref::from_i128(100, fp))
+            .awi;
+        error.lshr_(fp).unwrap();
+        let error = error.to_u128();
+        panic!(
+            "{context}, expected: {:?}, actual: {actual:?}, error: {}.{:02} ULPs",
+            expected.to_fracint::<F>(),
+            error / 100,
+            error % 100,
+        );
+    }
+}
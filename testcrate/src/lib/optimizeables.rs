@@ -65,7 +65,7 @@ impl<F: Fracint> Poly<F> {
         let mut res = self.a[len - 1];
         for i in (0..(len - 1)).rev() {
             // use wrapping ops because that's what we would be using in optimized curves
-            res = res.wrapping_mul(t).wrapping_add(self.a[i]);
+            res = res.wrapping_mul_add(t, self.a[i]);
         }
         res
     }
@@ -0,0 +1,234 @@
+//! The original `awint` based parser, kept as a differential reference for the
+//! dedicated parsers. Note that this allocates proportionally to the
+//! magnitude of the exponent, so it should not be used with large exponents.
+
+#![allow(clippy::manual_range_contains)]
+
+use std::num::NonZeroUsize;
+
+use awint::{Awi, InlAwi};
+use fracints::{Fracint, FracintSerdeError};
+use fracints_internals::reference::mag_from_awi;
+
+/// Takes a string, the bitwidth of the intermediate `Awi`, and the fixed point
+/// that the numerical value should be placed at
+fn awint_from_str(
+    s: &str,
+    bw: usize,
+    fp: isize,
+) -> Result<Result<(bool, Awi), awint::SerdeError>, FracintSerdeError> {
+    use FracintSerdeError::*;
+
+    let sign;
+    let integer;
+    let mut fraction = None;
+    let mut exp = None;
+    let mut exp_negative = false;
+    let radix;
+
+    let is_empty_or_all_underscores = |s: &[u8]| {
+        let mut all_underscores = true;
+        for c in s {
+            if *c != b'_' {
+                all_underscores = false;
+                break;
+            }
+        }
+        all_underscores
+    };
+
+    let is_integral = |c: u8, radix: u8| {
+        let is_underscore = c == b'_';
+        let is_binary = (b'0' <= c) && (c <= b'1');
+        let is_octal = (b'0' <= c) && (c <= b'7');
+        let is_decimal = (b'0' <= c) && (c <= b'9');
+        let is_lowerhex = (b'a' <= c) && (c <= b'f');
+        let is_upperhex = (b'A' <= c) && (c <= b'F');
+        match radix {
+            2 => is_underscore || is_binary,
+            8 => is_underscore || is_octal,
+            10 => is_underscore || is_decimal,
+            16 => is_underscore || is_decimal || is_lowerhex || is_upperhex,
+            _ => unreachable!(),
+        }
+    };
+
+    let s = s.as_bytes();
+    if is_empty_or_all_underscores(s) {
+        return Err(Empty);
+    }
+
+    // handle sign
+    let mut i = 0;
+    if s[i] == b'-' {
+        if s.len() <= 1 {
+            return Err(EmptyInteger);
+        }
+        sign = true;
+        i += 1;
+    } else {
+        sign = false;
+    }
+
+    // handle radix
+    if (s[i] == b'0') && ((i + 1) < s.len()) {
+        if s[i + 1] == b'b' {
+            radix = 2;
+            i += 2;
+        } else if s[i + 1] == b'o' {
+            radix = 8;
+            i += 2;
+        } else if s[i + 1] == b'x' {
+            radix = 16;
+            i += 2;
+        } else {
+            radix = 10;
+        }
+    } else {
+        radix = 10;
+    }
+
+    // integer part, can be followed by '.' for fraction, 'e' or 'p' for exponent
+    let integer_start = i;
+    let mut fraction_start = None;
+    let mut exp_start = None;
+    loop {
+        if i >= s.len() {
+            integer = &s[integer_start..i];
+            break;
+        }
+        if !is_integral(s[i], radix) {
+            if s[i] == b'.' {
+                fraction_start = Some(i + 1);
+            } else if (s[i] == b'e') || (s[i] == b'p') {
+                exp_start = Some(i + 1);
+            } else {
+                return Err(InvalidCharInInteger);
+            }
+            integer = &s[integer_start..i];
+            i += 1;
+            break;
+        }
+        i += 1;
+    }
+
+    // fraction part, can be followed by 'e' or 'p' for exponent
+    if let Some(fraction_start) = fraction_start {
+        loop {
+            if i >= s.len() {
+                fraction = Some(&s[fraction_start..i]);
+                break;
+            }
+            if !is_integral(s[i], radix) {
+                if (s[i] == b'e') || (s[i] == b'p') {
+                    exp_start = Some(i + 1);
+                } else {
+                    return Err(InvalidCharInFraction);
+                }
+                fraction = Some(&s[fraction_start..i]);
+                i += 1;
+                break;
+            }
+            i += 1;
+        }
+    }
+
+    // exponent part
+    if let Some(mut exp_start) = exp_start {
+        loop {
+            if i >= s.len() {
+                break;
+            }
+            if !is_integral(s[i], radix) {
+                if s[i] == b'-' {
+                    if exp_negative {
+                        return Err(InvalidCharInExponent);
+                    }
+                    exp_negative = true;
+                    exp_start += 1;
+                    i += 1;
+                    continue;
+                } else {
+                    return Err(InvalidCharInExponent);
+                }
+            }
+            i += 1;
+        }
+        exp = Some(&s[exp_start..i]);
+    }
+
+    if is_empty_or_all_underscores(integer) {
+        return Err(EmptyInteger);
+    }
+
+    if let Some(fraction) = fraction
+        && is_empty_or_all_underscores(fraction)
+    {
+        return Err(EmptyFraction);
+    }
+    let fraction = fraction.unwrap_or(&[]);
+
+    let pad0 = &mut InlAwi::from_usize(0);
+    let pad1 = &mut InlAwi::from_usize(0);
+    let mut usize_awi = InlAwi::from_usize(0);
+    let exp = if let Some(exp) = exp {
+        if is_empty_or_all_underscores(exp) {
+            return Err(EmptyExponent);
+        }
+        if usize_awi
+            .bytes_radix_(Some(exp_negative), exp, radix, pad0, pad1)
+            .is_err()
+        {
+            return Err(Overflow);
+        }
+        usize_awi.to_isize()
+    } else {
+        0
+    };
+
+    // note we handle the sign ourselves, the sign bit is instead room for ONE and
+    // NEG_ONE
+    Ok(Awi::from_bytes_general(
+        None,
+        integer,
+        fraction,
+        exp,
+        radix,
+        NonZeroUsize::new(bw).unwrap(),
+        fp,
+    )
+    .map(|res| (sign, res)))
+}
+
+/// Parses `s` into `F` using the `awint` reference
+pub fn ref_from_str<F: Fracint>(s: &str) -> Result<F, FracintSerdeError> {
+    use FracintSerdeError::*;
+    let (sign, awi) = if F::SIGNED {
+        awint_from_str(s, F::BITS, (F::BITS - 1) as isize)?
+    } else {
+        // one extra bit is used so that 1.0 can be detected
+        awint_from_str(s, F::BITS + 1, F::BITS as isize)?
+    }
+    .map_err(|_| Overflow)?;
+    if awi.is_zero() {
+        return Ok(F::ZERO)
+    }
+    let one_bit = if F::SIGNED { F::BITS - 1 } else { F::BITS };
+    let res = if awi.msb() {
+        // ONE and NEG_ONE special cases
+        if awi.tz() == one_bit {
+            F::ONE
+        } else {
+            return Err(Overflow)
+        }
+    } else {
+        mag_from_awi(&awi)
+    };
+    if !sign {
+        Ok(res)
+    } else if F::SIGNED {
+        Ok(-res)
+    } else {
+        Err(Overflow)
+    }
+}
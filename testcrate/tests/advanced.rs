@@ -1,5 +1,37 @@
-use common::sqrt::isqrt_sub1;
+use common::{assert_within_ulps, reference::Ref, sqrt::isqrt_sub1};
 use fracints::prelude::*;
+use star_rng::StarRng;
+
+/// Checks `sqrt_round` and `inv_sqrt_sub1` against the reference, `ulps` is
+/// the allowed error of `inv_sqrt_sub1`
+macro_rules! check_sqrt_round {
+    ($x:expr, $ty:ident, $fp:expr, $ulps:expr) => {{
+        let x: $ty = $x;
+        let x_ref = Ref::from_fracint(x, $fp);
+        if x.is_negative() {
+            assert_eq!(x.sqrt_round(), $ty::ZERO);
+        } else {
+            assert_within_ulps(
+                &x_ref.sqrt(),
+                x.sqrt_round(),
+                0.5,
+                format_args!("sqrt_round x: {x:?}"),
+            );
+        }
+        let one = Ref::from_i128(1, $fp);
+        if x_ref.sub(&one.div_int(4)).is_negative() {
+            assert_eq!(x.inv_sqrt_sub1(), $ty::ONE);
+        } else {
+            let expected = one.div(&x_ref.sqrt()).sub(&one);
+            assert_within_ulps(
+                &expected,
+                x.inv_sqrt_sub1(),
+                $ulps,
+                format_args!("inv_sqrt_sub1 x: {x:?}"),
+            );
+        }
+    }};
+}
 
 #[test]
 fn sqrt() {
@@ -37,6 +69,104 @@ fn sqrt() {
     );
     assert_eq!(fi64!(0.0).sqrt_fast(), fi64!(0.0));
     assert_eq!(fi64!(1.0).sqrt_fast(), fi64!(1.0));
+
+    assert_eq!(fu8!(0.5).sqrt_fast(), fu8!(0.7071));
+    assert_eq!(fu64!(0.07).sqrt_fast(), fu64!(0.26457513110645905905));
+    assert_eq!(
+        fu128!(0.11).sqrt_fast(),
+        fu128!(0.331662479035539984911493273667068668392)
+    );
+    assert_eq!(fu128::ULP.sqrt_fast(), fu128(1 << 64));
+    assert_eq!(fu64!(0.5).sqrt_slow(), fu64!(0.70710678118654752440));
+    for x in 0..=u16::MAX {
+        let x = fu16(x);
+        assert_eq!(x.sqrt_fast(), x.widen().sqrt_simple_bisection().truncate());
+    }
+
+    assert_eq!(fi128!(0.0).sqrt_slow(), fi128!(0.0));
+    assert_eq!(fi128!(0.25).sqrt_slow(), fi128!(0.5));
+    assert_eq!(
+        fi128!(0.5).sqrt_slow(),
+        fi128!(0.707106781186547524400844362104849039284)
+    );
+    assert_eq!(fi128!(1.0).sqrt_slow(), fi128!(1.0));
+    let mut rng = StarRng::new(0);
+    for _ in 0..1000 {
+        let x = fi256::rand(&mut rng).saturating_abs();
+        let expected = Ref::from_fracint(x, 512).sqrt().to_fracint::<fi256>();
+        let diff = expected.wrapping_sub(x.sqrt_fast()).wrapping_abs();
+        assert!(
+            diff <= fi256::ULP.saturating_mul_int(16i128.into()),
+            "x: {x:?}"
+        );
+    }
+}
+
+#[test]
+fn sqrt_fast_fi8_exhaustive() {
+    for i in i8::MIN..=i8::MAX {
+        let x = fi8(i);
+        let expected = if i <= 0 {
+            fi8::ZERO
+        } else {
+            fi8(((i as u16) << 7).isqrt() as i8)
+        };
+        assert_eq!(x.sqrt_fast(), expected);
+        assert_eq!(x.sqrt_fast(), x.sqrt_slow());
+    }
+}
+
+#[test]
+fn sqrt_round() {
+    for i in i8::MIN..=i8::MAX {
+        check_sqrt_round!(fi8(i), fi8, 64, 1.0);
+    }
+    for i in i16::MIN..=i16::MAX {
+        check_sqrt_round!(fi16(i), fi16, 64, 1.0);
+    }
+    let mut rng = StarRng::new(0);
+    for _ in 0..1000 {
+        check_sqrt_round!(fi32::rand(&mut rng), fi32, 320, 1.0);
+        check_sqrt_round!(fi64::rand(&mut rng), fi64, 320, 1.0);
+        check_sqrt_round!(fi128::rand(&mut rng), fi128, 320, 1.0);
+    }
+    for i in 0..127 {
+        check_sqrt_round!(fi128::ULP << i, fi128, 320, 1.0);
+        check_sqrt_round!(fi128::ONE >> i, fi128, 320, 1.0);
+    }
+    for i in u8::MIN..=u8::MAX {
+        check_sqrt_round!(fu8(i), fu8, 64, 1.0);
+    }
+    for i in u16::MIN..=u16::MAX {
+        check_sqrt_round!(fu16(i), fu16, 64, 1.0);
+    }
+    for _ in 0..1000 {
+        check_sqrt_round!(fu32::rand(&mut rng), fu32, 320, 1.0);
+        check_sqrt_round!(fu64::rand(&mut rng), fu64, 320, 1.0);
+        check_sqrt_round!(fu128::rand(&mut rng), fu128, 320, 1.0);
+    }
+    for i in 0..128 {
+        check_sqrt_round!(fu64::ULP << (i % 64), fu64, 320, 1.0);
+        check_sqrt_round!(fu64::ONE >> (i % 64), fu64, 320, 1.0);
+        check_sqrt_round!(fu128::ULP << i, fu128, 320, 1.0);
+        check_sqrt_round!(fu128::ONE >> i, fu128, 320, 1.0);
+    }
+    assert_eq!(fi32::ONE.sqrt_round(), fi32::ONE);
+    assert_eq!(fi128::ONE.sqrt_round(), fi128::ONE);
+    assert_eq!(fi8::ULP.sqrt_round(), fi8(11));
+    assert_eq!(fi64!(0.5).sqrt_round(), fi64!(0.70710678118654752440));
+    assert_eq!(fi16!(0.25).inv_sqrt_sub1(), fi16::ONE);
+    assert_eq!(fi16!(0.1).inv_sqrt_sub1(), fi16::ONE);
+    assert_eq!(fi16!(0.5).inv_sqrt_sub1(), fi16!(0.41421));
+    assert_eq!(fi16::ONE.inv_sqrt_sub1(), fi16::ZERO);
+    assert_eq!(fu8::ONE.sqrt_round(), fu8::ONE);
+    assert_eq!(fu128::ONE.sqrt_round(), fu128::ONE);
+    assert_eq!(fu8::ULP.sqrt_round(), fu8(16));
+    assert_eq!(fu64!(0.5).sqrt_round(), fu64!(0.70710678118654752440));
+    assert_eq!(fu16!(0.25).inv_sqrt_sub1(), fu16::ONE);
+    assert_eq!(fu16!(0.1).inv_sqrt_sub1(), fu16::ONE);
+    assert_eq!(fu16!(0.5).inv_sqrt_sub1(), fu16!(0.41421));
+    assert_eq!(fu16::ONE.inv_sqrt_sub1(), fu16::ZERO);
 }
 
 #[test]
@@ -59,4 +189,236 @@ fn float_conv() {
 
     assert_eq!(fi128!(0.123).to_f32(), 0.122999996);
     assert_eq!(fi128!(0.123).to_f64(), 0.123);
+
+    assert!(fu16::from_f64(1.0000001).is_none());
+    assert!(fu16::from_f64(-0.0000001).is_none());
+    assert_eq!(fu16::from_f64(1.0).unwrap(), fu16::ONE);
+    assert_eq!(fu16::from_f64(0.0).unwrap(), fu16::ZERO);
+    assert_eq!(fu16::from_f64(0.5).unwrap(), fu16(1 << 15));
+    assert_eq!(fu16::from_f32(0.75).unwrap(), fu16(3 << 14));
+    assert_eq!(fu128!(0.123).to_f32(), 0.122999996);
+    assert_eq!(fu128!(0.123).to_f64(), 0.123);
+    assert_eq!(fu8::ONE.to_f64(), 255.0 / 256.0);
+
+    // `2^-128` is a subnormal `f32`
+    let tiny = f32::from_bits(1 << 21);
+    assert_eq!(fu128::ULP.to_f32(), tiny);
+    assert_eq!(fu128::from_f32(tiny).unwrap(), fu128::ULP);
+    assert_eq!((-fi256::ULP).to_f32(), -0.0);
+    assert_eq!(fi128::from_f32(-tiny * 2.0).unwrap(), -fi128::ULP);
+}
+
+/// Divides with exact integer arithmetic according to `mode`
+fn round_div(num: i32, den: i32, mode: RoundingMode) -> i32 {
+    let (quo, rem) = (num.div_euclid(den), num.rem_euclid(den));
+    // `quo` is now the floor if `den` is positive
+    let (quo, rem, den) = if den < 0 {
+        if rem == 0 {
+            (quo, 0, -den)
+        } else {
+            (quo - 1, -den - rem, -den)
+        }
+    } else {
+        (quo, rem, den)
+    };
+    let up = match mode {
+        RoundingMode::Floor => false,
+        RoundingMode::Ceil => rem != 0,
+        RoundingMode::TowardZero => (rem != 0) && (quo < 0),
+        RoundingMode::NearestEven => (2 * rem > den) || ((2 * rem == den) && ((quo & 1) != 0)),
+    };
+    quo + (up as i32)
+}
+
+#[test]
+fn rounding_modes() {
+    use RoundingMode::*;
+    for mode in [Floor, Ceil, TowardZero, NearestEven] {
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                let (x, y) = (fi8(a), fi8(b));
+                let expected = if (x == fi8::MIN) && (y == fi8::MIN) {
+                    fi8::ONE
+                } else {
+                    fi8(round_div(i32::from(a) * i32::from(b), 1 << 7, mode) as i8)
+                };
+                assert_eq!(x.mul_with(y, mode), expected, "{x:?} {y:?} {mode:?}");
+                let expected = if (b == 0) || (i32::from(a).abs() >= i32::from(b).abs()) {
+                    x.saturating_div(y)
+                } else {
+                    let quo = round_div(i32::from(a) << 7, i32::from(b), mode);
+                    fi8(quo.clamp(-127, 127) as i8)
+                };
+                assert_eq!(x.div_with(y, mode), expected, "{x:?} {y:?} {mode:?}");
+            }
+        }
+        for a in u8::MIN..=u8::MAX {
+            for b in u8::MIN..=u8::MAX {
+                let (x, y) = (fu8(a), fu8(b));
+                let expected = round_div(i32::from(a) * i32::from(b), 1 << 8, mode);
+                assert_eq!(x.mul_with(y, mode), fu8(expected as u8));
+                let expected = if (b == 0) || (a >= b) {
+                    x.saturating_div(y)
+                } else {
+                    let quo = round_div(i32::from(a) << 8, i32::from(b), mode);
+                    fu8(quo.min(255) as u8)
+                };
+                assert_eq!(x.div_with(y, mode), expected, "{x:?} {y:?} {mode:?}");
+            }
+        }
+    }
+
+    // the 128 bit types have their own implementations
+    let mut rng = StarRng::new(0);
+    for _ in 0..1000 {
+        let (x, y) = (fi128::rand(&mut rng), fi128::rand(&mut rng) >> 1);
+        let (x_ref, y_ref) = (Ref::from_fracint(x, 320), Ref::from_fracint(y, 320));
+        let floor = x.mul_with(y, Floor);
+        let ceil = x.mul_ceil(y);
+        // `fi128` products are truncated towards zero
+        assert_eq!(x.mul_with(y, TowardZero), x * y);
+        assert_eq!(x.mul_with(y, TowardZero), x.wrapping_mul(y));
+        assert_eq!(x.mul_round(y), x_ref.mul(&y_ref).to_fracint::<fi128>());
+        assert_eq!(
+            x.mul_with(y, TowardZero),
+            if x.is_negative() != y.is_negative() {
+                ceil
+            } else {
+                floor
+            }
+        );
+        assert!(ceil.wrapping_sub(floor) <= fi128::ULP);
+        if y.saturating_abs() > x.saturating_abs() {
+            assert_eq!(x.div_round(y), x_ref.div(&y_ref).to_fracint::<fi128>());
+        }
+        let (x, y) = (fu128::rand(&mut rng), fu128::rand(&mut rng));
+        let (x_ref, y_ref) = (Ref::from_fracint(x, 320), Ref::from_fracint(y, 320));
+        assert_eq!(x.mul_round(y), x_ref.mul(&y_ref).to_fracint::<fu128>());
+        assert!(x.mul_ceil(y).wrapping_sub(x * y) <= fu128::ULP);
+        if y > x {
+            assert_eq!(x.div_round(y), x_ref.div(&y_ref).to_fracint::<fu128>());
+        }
+    }
+    assert_eq!(fi128::MIN.mul_round(fi128::MIN), fi128::ONE);
+    assert_eq!(fi128(-3).mul_round(fi128!(0.5)), fi128(-2));
+    assert_eq!(fi128(-3).mul_ceil(fi128!(0.5)), fi128(-1));
+    assert_eq!(fi128(-3).mul_with(fi128!(0.5), Floor), fi128(-2));
+    assert_eq!(fi128(-3) * fi128!(0.5), fi128(-1));
+    assert_eq!(fi128(-3).saturating_mul(fi128!(0.5)), fi128(-1));
+    assert_eq!(fi128(3).wrapping_mul(fi128!(-0.5)), fi128(-1));
+    assert_eq!(fi128::MIN.wrapping_mul(fi128::MIN), fi128::MIN);
+    assert_eq!(fi128::MAX.div_ceil(fi128::MIN), fi128::NEG_ONE);
+    assert_eq!((fi128::ONE - fi128::ULP).div_ceil(fi128::ONE), fi128::ONE);
+
+    for _ in 0..1000 {
+        let (x, y) = (fi256::rand(&mut rng), fi256::rand(&mut rng) >> 1);
+        let (x_ref, y_ref) = (Ref::from_fracint(x, 512), Ref::from_fracint(y, 512));
+        let floor = x.mul_with(y, Floor);
+        let ceil = x.mul_ceil(y);
+        assert_eq!(floor, x * y);
+        assert_eq!(x.mul_round(y), x_ref.mul(&y_ref).to_fracint::<fi256>());
+        assert_eq!(
+            x.mul_with(y, TowardZero),
+            if x.is_negative() != y.is_negative() {
+                ceil
+            } else {
+                floor
+            }
+        );
+        assert!(ceil.wrapping_sub(floor) <= fi256::ULP);
+        if y.saturating_abs() > x.saturating_abs() {
+            assert_eq!(x.div_round(y), x_ref.div(&y_ref).to_fracint::<fi256>());
+        }
+    }
+    assert_eq!(fi256::MIN.mul_round(fi256::MIN), fi256::ONE);
+    assert_eq!(fi256::MAX.div_ceil(fi256::MIN), fi256::NEG_ONE);
+}
+
+#[test]
+fn accumulator() {
+    let mut rng = StarRng::new(0);
+    for len in 0..64 {
+        // exact integer arithmetic in units of `ULP * ULP`
+        let x: Vec<fi8> = (0..len).map(|_| fi8::rand(&mut rng)).collect();
+        let y: Vec<fi8> = (0..len).map(|_| fi8::rand(&mut rng)).collect();
+        let sum: i64 = x
+            .iter()
+            .zip(&y)
+            .map(|(x, y)| i64::from(x.0) * i64::from(y.0))
+            .sum();
+        let expected = round_div(sum as i32, 1 << 7, RoundingMode::NearestEven);
+        assert_eq!(dot(&x, &y), fi8(expected.clamp(-127, 127) as i8));
+        let mut acc = Accumulator::new();
+        for (x, y) in x.iter().zip(&y) {
+            acc.add_product(*x, *y);
+        }
+        assert_eq!(
+            acc.round(),
+            ((-127..=127).contains(&expected)).then_some(fi8(expected as i8))
+        );
+        for x in &x {
+            acc.add(*x);
+        }
+        let sum = sum + (x.iter().map(|x| i64::from(x.0)).sum::<i64>() << 7);
+        let expected = round_div(sum as i32, 1 << 7, RoundingMode::NearestEven);
+        assert_eq!(acc.saturate(), fi8(expected.clamp(-127, 127) as i8));
+
+        let x: Vec<fu8> = (0..len).map(|_| fu8::rand(&mut rng)).collect();
+        let y: Vec<fu8> = (0..len).map(|_| fu8::rand(&mut rng)).collect();
+        let sum: i64 = x
+            .iter()
+            .zip(&y)
+            .map(|(x, y)| i64::from(x.0) * i64::from(y.0))
+            .sum();
+        let expected = round_div(sum as i32, 1 << 8, RoundingMode::NearestEven);
+        assert_eq!(dot(&x, &y), fu8(expected.min(255) as u8));
+
+        // compare with the reference
+        let x: Vec<fi64> = (0..len).map(|_| fi64::rand(&mut rng) >> 3).collect();
+        let y: Vec<fi64> = (0..len).map(|_| fi64::rand(&mut rng)).collect();
+        let mut sum = Ref::zero(192);
+        for (x, y) in x.iter().zip(&y) {
+            sum = sum.add(&Ref::from_fracint(*x, 192).mul(&Ref::from_fracint(*y, 192)));
+        }
+        let expected = if sum.abs().awi.sig() > 192 {
+            if sum.is_negative() {
+                fi64::NEG_ONE
+            } else {
+                fi64::ONE
+            }
+        } else {
+            sum.to_fracint::<fi64>()
+        };
+        assert_eq!(dot(&x, &y), expected);
+
+        // `fi128` accumulates in `fi256`
+        let x: Vec<fi128> = (0..len).map(|_| fi128::rand(&mut rng) >> 3).collect();
+        let y: Vec<fi128> = (0..len).map(|_| fi128::rand(&mut rng)).collect();
+        let mut sum = Ref::zero(384);
+        for (x, y) in x.iter().zip(&y) {
+            sum = sum.add(&Ref::from_fracint(*x, 384).mul(&Ref::from_fracint(*y, 384)));
+        }
+        let expected = if sum.abs().awi.sig() > 384 {
+            if sum.is_negative() {
+                fi128::NEG_ONE
+            } else {
+                fi128::ONE
+            }
+        } else {
+            sum.to_fracint::<fi128>()
+        };
+        assert_eq!(dot(&x, &y), expected);
+    }
+    // the corner case of `fiN::MIN * fiN::MIN`
+    let mut acc = Accumulator::new();
+    acc.add_product(fi32::MIN, fi32::MIN);
+    assert_eq!(acc.round(), None);
+    acc.add(fi32!(-0.5));
+    assert_eq!(acc.round(), Some(fi32!(0.5)));
+    acc.add(fi32::MIN);
+    assert_eq!(acc.round(), Some(fi32!(-0.5)));
+    acc.add(fi32!(-0.5));
+    assert_eq!(acc.round(), None);
+    assert_eq!(acc.saturate(), fi32::NEG_ONE);
+    assert_eq!(dot::<fu16>(&[], &[]), fu16::ZERO);
 }
@@ -0,0 +1,71 @@
+use fracints::prelude::*;
+
+#[test]
+fn angle_wrapping() {
+    let a = Angle(fi16!(0.75));
+    assert_eq!(a + a, Angle(fi16!(-0.5)));
+    assert_eq!(-a - a, Angle(fi16!(0.5)));
+    assert_eq!(Angle(fi16::MIN) + Angle(fi16::ZERO), Angle::HALF_TURN);
+    assert_eq!(-Angle(fi16::MIN), Angle(fi16::MIN));
+    assert_eq!(Angle(fi16::ONE) + Angle(fi16::ULP), Angle(fi16::MIN));
+    let mut b = Angle(fi8::ZERO);
+    for _ in 0..256 {
+        b += Angle(fi8::ULP);
+    }
+    assert_eq!(b, Angle::ZERO);
+    b -= Angle(fi8::ULP);
+    assert_eq!(b, Angle(fi8(-1)));
+}
+
+#[test]
+fn angle_conversions() {
+    assert_eq!(Angle::<fi32>::from_degrees(0.0), Angle::ZERO);
+    assert_eq!(Angle::<fi32>::from_degrees(90.0), Angle(fi32!(0.5)));
+    assert_eq!(Angle::<fi32>::from_degrees(-90.0), Angle(fi32!(-0.5)));
+    assert_eq!(Angle::<fi32>::from_degrees(180.0), Angle(fi32::MIN));
+    assert_eq!(Angle::<fi32>::from_degrees(-180.0), Angle(fi32::MIN));
+    assert_eq!(Angle::<fi32>::from_degrees(540.0), Angle(fi32::MIN));
+    assert_eq!(Angle::<fi32>::from_degrees(450.0), Angle(fi32!(0.5)));
+    assert_eq!(Angle::<fi32>::from_degrees(-270.0), Angle(fi32!(0.5)));
+    assert_eq!(Angle::<fi32>::from_degrees(270.0), Angle(fi32!(-0.5)));
+    assert_eq!(
+        Angle::<fi32>::from_radians(core::f64::consts::FRAC_PI_4),
+        Angle(fi32!(0.25))
+    );
+    assert_eq!(Angle::<fi32>::from_half_turns_f64(f64::NAN), None);
+    assert_eq!(Angle::<fi32>::from_half_turns_f64(f64::INFINITY), None);
+    assert_eq!(Angle(fi32::MIN).to_degrees(), -180.0);
+    assert_eq!(Angle(fi32!(0.5)).to_degrees(), 90.0);
+    assert_eq!(
+        Angle(fi32!(-0.25)).to_radians(),
+        -core::f64::consts::FRAC_PI_4
+    );
+    for deg in -180..180 {
+        let a = Angle::<fi64>::from_degrees(deg as f64);
+        assert!((a.to_degrees() - (deg as f64)).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn angle_distance() {
+    let a = Angle(fi16!(0.9));
+    let b = Angle(fi16!(-0.9));
+    assert_eq!(a.distance(b), fi16!(0.2));
+    assert_eq!(b.distance(a), fi16!(0.2));
+    assert_eq!(a.distance(a), fi16::ZERO);
+    assert_eq!(Angle(fi16::ZERO).distance(Angle(fi16::MIN)), fi16::ONE);
+    assert_eq!(Angle(fi16!(0.5)).distance(Angle(fi16!(-0.5))), fi16::ONE);
+}
+
+#[test]
+fn angle_cos_sin_and_display() {
+    assert_eq!(Angle(fi32::ZERO).cos_sin(), (fi32::ONE, fi32::ZERO));
+    assert_eq!(Angle(fi32::MIN).cos_sin(), (fi32::NEG_ONE, fi32::ZERO));
+    assert_eq!(
+        Angle::<fi32>::from_degrees(90.0).cos_sin(),
+        (fi32::ZERO, fi32::ONE)
+    );
+    assert_eq!(format!("{}", Angle(fi32!(0.5))), "90°");
+    assert_eq!(format!("{}", Angle(fi32::MIN)), "-180°");
+    assert_eq!(format!("{:.2}", Angle(fi32!(0.25))), "45.00°");
+}
@@ -1,7 +1,8 @@
 use std::str::FromStr;
 
 use fracints::*;
-use stacked_errors::{Result, ensure_eq};
+use stacked_errors::{Result, ensure, ensure_eq};
+use star_rng::StarRng;
 
 macro_rules! basic_cases {
     ($ty:ident, $iX:ident) => {
@@ -92,3 +93,292 @@ fn basic_cases() -> Result<()> {
     basic_cases!(fi128, i128);
     Ok(())
 }
+
+macro_rules! basic_cases_unsigned {
+    ($ty:ident, $uX:ident) => {
+        ensure_eq!($ty($uX::MAX / 2 + 1).to_string(), "0.5".to_string());
+        ensure_eq!($ty::from_str("0.5").unwrap(), $ty($uX::MAX / 2 + 1));
+        ensure_eq!($ty::from_str("-0.0").unwrap(), $ty::ZERO);
+        ensure_eq!(
+            $ty::from_str("-0.5").unwrap_err(),
+            FracintSerdeError::Overflow
+        );
+        ensure_eq!(
+            $ty::from_str("1.5").unwrap_err(),
+            FracintSerdeError::Overflow
+        );
+        ensure_eq!($ty::MIN, $ty::ZERO);
+        ensure_eq!($ty::ZERO.to_string(), "0.0".to_string());
+        ensure_eq!($ty::ONE.to_string(), "1.0".to_string());
+        ensure_eq!($ty::from_str("0.0").unwrap(), $ty::ZERO);
+        ensure_eq!($ty::from_str("1.0").unwrap(), $ty::ONE);
+
+        ensure_eq!($ty($uX::MAX / 4 + 1) + $ty($uX::MAX / 4), $ty($uX::MAX / 2));
+        ensure_eq!($ty::ONE + $ty::ULP, $ty::ONE);
+        ensure_eq!($ty::ZERO - $ty::ULP, $ty::ZERO);
+        ensure_eq!($ty::ZERO.wrapping_sub($ty::ULP), $ty::MAX);
+        ensure_eq!($ty::ONE.overflowing_add($ty::ULP), ($ty::ZERO, true));
+        ensure_eq!(-$ty::ONE, $ty::ZERO);
+        ensure_eq!($ty::ULP.checked_neg(), None);
+
+        ensure_eq!(
+            $ty($uX::MAX / 2 + 1) * $ty($uX::MAX / 2 + 1),
+            $ty($uX::MAX / 4 + 1)
+        );
+        ensure_eq!($ty::ONE * $ty::ONE, $ty($uX::MAX - 1));
+        ensure_eq!($ty::ONE * $ty::ULP, $ty::ZERO);
+
+        ensure_eq!(
+            $ty($uX::MAX / 4 + 1).saturating_div($ty($uX::MAX / 2 + 1)),
+            $ty($uX::MAX / 2 + 1)
+        );
+        ensure_eq!($ty::ONE.saturating_div($ty::ONE), $ty::ONE);
+        ensure_eq!($ty::ULP.saturating_div($ty::ZERO), $ty::ONE);
+        ensure_eq!($ty::ZERO.saturating_div($ty::ZERO), $ty::ZERO);
+        ensure_eq!($ty::ONE.saturating_div_int(2), $ty($uX::MAX / 2));
+        ensure_eq!($ty($uX::MAX / 2).saturating_mul_int(3), $ty::ONE);
+
+        ensure_eq!($ty($uX::MAX / 4 + 1).sqrt_fast(), $ty($uX::MAX / 2 + 1));
+        ensure_eq!($ty::ONE.sqrt_fast(), $ty::ONE);
+        ensure_eq!($ty::ZERO.sqrt_fast(), $ty::ZERO);
+    };
+}
+
+#[test]
+fn basic_cases_unsigned() -> Result<()> {
+    basic_cases_unsigned!(fu8, u8);
+    basic_cases_unsigned!(fu16, u16);
+    basic_cases_unsigned!(fu32, u32);
+    basic_cases_unsigned!(fu64, u64);
+    basic_cases_unsigned!(fu128, u128);
+    Ok(())
+}
+
+macro_rules! div_cases {
+    ($ty:ident, $iX:ident) => {
+        let half = $ty($iX::MAX / 2 + 1);
+        let quarter = $ty($iX::MAX / 4 + 1);
+        ensure_eq!(quarter.wrapping_div(half), half);
+        ensure_eq!(quarter.overflowing_div(-half), (-half, false));
+        ensure_eq!(quarter.checked_div(half), Some(half));
+        ensure_eq!(half.checked_div(half), None);
+        ensure_eq!(half.checked_div(-half), None);
+        ensure_eq!(half.checked_div($ty::ZERO), None);
+        ensure_eq!((-half).overflowing_div(half), ($ty::MIN, true));
+        ensure_eq!($ty::MAX.overflowing_div($ty::MIN), ($ty::NEG_ONE, false));
+        ensure_eq!($ty::MIN.overflowing_div($ty::MIN), ($ty::MIN, true));
+        ensure_eq!($ty::ULP.div_rem($ty::MAX), ($ty::ULP, $ty::ULP));
+        ensure_eq!((-$ty::ULP).div_rem($ty::MAX), (-$ty::ULP, -$ty::ULP));
+        ensure_eq!(quarter.div_rem(-half), (-half, $ty::ZERO));
+
+        ensure_eq!($ty::MIN.wrapping_div_int(-1), $ty::MIN);
+        ensure_eq!($ty::MIN.overflowing_div_int(-1), ($ty::MIN, true));
+        ensure_eq!($ty::MIN.checked_div_int(-1), None);
+        ensure_eq!($ty::MIN.checked_div_int(0), None);
+        ensure_eq!($ty::MIN.checked_div_int(2), Some(-half));
+        ensure_eq!(
+            $ty($iX::MAX).div_rem_int(-2),
+            (-$ty($iX::MAX / 2), $ty::ULP)
+        );
+        ensure_eq!($ty(-7).div_rem_int(2), ($ty(-3), $ty(-1)));
+    };
+}
+
+#[test]
+fn div_family() -> Result<()> {
+    div_cases!(fi8, i8);
+    div_cases!(fi16, i16);
+    div_cases!(fi32, i32);
+    div_cases!(fi64, i64);
+    div_cases!(fi128, i128);
+
+    // exhaustive comparison with exact integer arithmetic
+    for lhs in i8::MIN..=i8::MAX {
+        for rhs in (i8::MIN..=i8::MAX).filter(|rhs| *rhs != 0) {
+            let num = i32::from(lhs) << 7;
+            let (quo, rem) = (num / i32::from(rhs), num % i32::from(rhs));
+            let overflow = (quo < -i32::from(i8::MAX)) || (quo > i32::from(i8::MAX));
+            ensure_eq!(
+                fi8(lhs).overflowing_div(fi8(rhs)),
+                (fi8(quo as i8), overflow)
+            );
+            ensure_eq!(fi8(lhs).div_rem(fi8(rhs)), (fi8(quo as i8), fi8(rem as i8)));
+            ensure_eq!(
+                fi8(lhs).checked_div(fi8(rhs)),
+                (!overflow).then_some(fi8(quo as i8))
+            );
+        }
+    }
+    for lhs in u8::MIN..=u8::MAX {
+        for rhs in 1..=u8::MAX {
+            let num = u32::from(lhs) << 8;
+            let (quo, rem) = (num / u32::from(rhs), num % u32::from(rhs));
+            let overflow = quo > u32::from(u8::MAX);
+            ensure_eq!(
+                fu8(lhs).overflowing_div(fu8(rhs)),
+                (fu8(quo as u8), overflow)
+            );
+            ensure_eq!(fu8(lhs).div_rem(fu8(rhs)), (fu8(quo as u8), fu8(rem as u8)));
+            ensure_eq!(fu8(lhs).div_rem_int(rhs), (fu8(lhs / rhs), fu8(lhs % rhs)));
+        }
+    }
+
+    // the 128 bit remainders must reconstruct the dividend modulo `2^128`
+    let cases = [
+        (fi128::ULP, fi128::MAX),
+        (fi128::MIN, fi128::MIN),
+        (fi128!(0.3), fi128!(-0.7)),
+        (fi128!(-0.123456789), fi128!(0.987654321)),
+    ];
+    for (lhs, rhs) in cases {
+        let (quo, rem) = lhs.div_rem(rhs);
+        ensure_eq!(quo.0.wrapping_mul(rhs.0).wrapping_add(rem.0), lhs.0 << 127);
+        ensure!(rem.0.unsigned_abs() < rhs.0.unsigned_abs());
+        ensure!(rem.is_zero() || (rem.is_negative() == lhs.is_negative()));
+    }
+    let (quo, rem) = fu128!(0.3).div_rem(fu128!(0.7));
+    ensure_eq!(quo.0.wrapping_mul(fu128!(0.7).0).wrapping_add(rem.0), 0);
+    ensure!(rem < fu128!(0.7));
+    Ok(())
+}
+
+#[test]
+fn mul_add() -> Result<()> {
+    // exhaustive comparison with exact integer arithmetic
+    for x in i8::MIN..=i8::MAX {
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                let res = ((i32::from(x) * i32::from(a)) >> 7) + i32::from(b);
+                let overflow = (res <= i32::from(i8::MIN)) || (res > i32::from(i8::MAX));
+                let (x, a, b) = (fi8(x), fi8(a), fi8(b));
+                ensure_eq!(x.overflowing_mul_add(a, b), (fi8(res as i8), overflow));
+                ensure_eq!(x.wrapping_mul_add(a, b), x.wrapping_mul(a).wrapping_add(b));
+                ensure_eq!(
+                    x.saturating_mul_add(a, b),
+                    fi8(res.clamp(-i32::from(i8::MAX), i32::from(i8::MAX)) as i8)
+                );
+                ensure_eq!(
+                    x.checked_mul_add(a, b),
+                    (!overflow).then_some(fi8(res as i8))
+                );
+            }
+        }
+    }
+    for x in (u8::MIN..=u8::MAX).step_by(3) {
+        for a in u8::MIN..=u8::MAX {
+            for b in (u8::MIN..=u8::MAX).step_by(5) {
+                let res = ((u32::from(x) * u32::from(a)) >> 8) + u32::from(b);
+                let (x, a, b) = (fu8(x), fu8(a), fu8(b));
+                ensure_eq!(
+                    x.overflowing_mul_add(a, b),
+                    (fu8(res as u8), res > u32::from(u8::MAX))
+                );
+                ensure_eq!(x.saturating_mul_add(a, b), fu8(res.min(255) as u8));
+            }
+        }
+    }
+
+    // the 256 bit intermediate product of `fi128`
+    ensure_eq!(
+        fi128::MIN.overflowing_mul_add(fi128::MIN, fi128::NEG_ONE),
+        (fi128::ULP, false)
+    );
+    ensure_eq!(fi128::MIN.checked_mul_add(fi128::MIN, fi128::ZERO), None);
+    ensure_eq!(
+        fi128::MIN.saturating_mul_add(fi128::MAX, fi128::ULP),
+        fi128::NEG_ONE + fi128::ULP
+    );
+    ensure_eq!(
+        fi128!(-0.5).saturating_mul_add(fi128!(0.5), fi128!(-0.75)),
+        fi128::NEG_ONE
+    );
+    ensure_eq!(
+        fi128(-3).wrapping_mul_add(fi128!(0.5), fi128(2)),
+        fi128::ZERO
+    );
+    // the separately rounded `fi128` product is truncated towards zero
+    ensure_eq!(fi128(-3) * fi128!(0.5) + fi128(2), fi128::ULP);
+    let mut rng = StarRng::new(0);
+    for _ in 0..1000 {
+        let (x, a, b) = (
+            fi128::rand(&mut rng),
+            fi128::rand(&mut rng),
+            fi128::rand(&mut rng),
+        );
+        // the exact sum in double width truncated once
+        let exact = x.saturating_widening_mul(a).wrapping_add(b.widen());
+        ensure_eq!(x.wrapping_mul_add(a, b), exact.truncate());
+    }
+    ensure_eq!(
+        fu128::ONE.saturating_mul_add(fu128::ONE, fu128::ULP),
+        fu128::ONE
+    );
+    ensure_eq!(
+        fu128::ONE.overflowing_mul_add(fu128::ONE, fu128(2)),
+        (fu128::ZERO, true)
+    );
+    Ok(())
+}
+
+#[test]
+fn fi256_cases() -> Result<()> {
+    let half = fi256::from(fi128!(0.5));
+    let quarter = fi256::from(fi128!(0.25));
+    ensure_eq!(
+        half.0,
+        i256::MAX
+            .wrapping_div(2i128.into())
+            .wrapping_add(1i128.into())
+    );
+    ensure_eq!(half.to_string(), "0.5".to_string());
+    ensure_eq!((-half).to_string(), "-0.5".to_string());
+    ensure_eq!(fi256::from_str("0.5").unwrap(), half);
+    ensure_eq!(fi256::from_str("-0.25").unwrap(), -quarter);
+    ensure_eq!(fi256::MIN.to_string(), "-1.0".to_string());
+    ensure_eq!(fi256::NEG_ONE.to_string(), "-1.0".to_string());
+    ensure_eq!(fi256::ZERO.to_string(), "0.0".to_string());
+    ensure_eq!(fi256::ONE.to_string(), "1.0".to_string());
+    ensure_eq!(fi256::from_str("1.0").unwrap(), fi256::ONE);
+
+    ensure_eq!(quarter + quarter, half);
+    ensure_eq!(half - quarter, quarter);
+    ensure_eq!(half + half, fi256::ONE);
+    ensure_eq!(-half - half, fi256::NEG_ONE);
+    ensure_eq!(half.overflowing_add(half), (fi256::MIN, true));
+    ensure_eq!(half * half, quarter);
+    ensure_eq!(fi256::MIN * fi256::MIN, fi256::ONE);
+    ensure_eq!(fi256::MIN.checked_mul(fi256::MIN), None);
+    ensure_eq!(quarter / half, half);
+    ensure_eq!(fi256::ONE.checked_div(fi256::ULP), None);
+    ensure_eq!(quarter * i256::from(3i128), half + quarter);
+
+    // the remainders must reconstruct the dividend modulo `2^256`
+    let cases = [
+        (fi256::ULP, fi256::MAX),
+        (fi256::MIN, fi256::MIN),
+        (fi256::from(fi128!(0.3)), fi256::from(fi128!(-0.7))),
+        (
+            -fi256::from(fi128!(0.123456789)),
+            fi256::from(fi128!(0.987654321)),
+        ),
+    ];
+    for (lhs, rhs) in cases {
+        let (quo, rem) = lhs.div_rem(rhs);
+        ensure_eq!(quo.0.wrapping_mul(rhs.0).wrapping_add(rem.0), lhs.0 << 255);
+        ensure!(rem.0.unsigned_abs() < rhs.0.unsigned_abs());
+        ensure!(rem.is_zero() || (rem.is_negative() == lhs.is_negative()));
+    }
+
+    // `fi128` is the half of `fi256`
+    let x = fi128!(-0.75);
+    ensure_eq!(x.widen(), fi256::from(x));
+    ensure_eq!(x.widen().split(), (fi128::ZERO, x));
+    ensure_eq!(x.widen().truncate(), x);
+    ensure_eq!(fi128!(0.5).saturating_widening_mul(fi128!(0.5)), quarter);
+    ensure_eq!(fi128::MIN.saturating_widening_mul(fi128::MIN), fi256::ONE);
+    ensure_eq!(
+        fi128::ULP.saturating_widening_mul(fi128::ULP).split(),
+        (fi128(2), fi128::ZERO)
+    );
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use common::{assert_within_ulps, reference::Ref};
+use fracints::prelude::*;
+use star_rng::StarRng;
+
+/// Checks `cordic_cos_sin` against the reference
+macro_rules! check_cos_sin {
+    ($x:expr, $ty:ident, $pi:expr, $ulps:expr) => {{
+        let x: $ty = $x;
+        let pi: &Ref = $pi;
+        let (cos, sin) = Ref::from_fracint(x, pi.fp).mul(pi).cos_sin();
+        let (cos_x, sin_x) = x.cordic_cos_sin();
+        assert_within_ulps(&cos, cos_x, $ulps, format_args!("cos x: {x:?}"));
+        assert_within_ulps(&sin, sin_x, $ulps, format_args!("sin x: {x:?}"));
+    }};
+}
+
+/// Checks `cordic_atan2` and `cordic_hypot` against the reference
+macro_rules! check_polar {
+    ($y:expr, $x:expr, $ty:ident, $pi:expr, $ulps:expr) => {{
+        let y: $ty = $y;
+        let x: $ty = $x;
+        let pi: &Ref = $pi;
+        let fp = pi.fp;
+        let y_ref = Ref::from_fracint(y, fp);
+        let x_ref = Ref::from_fracint(x, fp);
+        let mut expected = Ref::atan2(&y_ref, &x_ref, pi).div(pi);
+        let actual = y.cordic_atan2(x);
+        // `MIN` and `ONE` both stand for a half turn
+        if (actual == $ty::MIN) && !expected.is_negative() {
+            expected = expected.sub(&Ref::from_i128(2, fp));
+        }
+        assert_within_ulps(
+            &expected,
+            actual,
+            $ulps,
+            format_args!("atan2 y: {y:?}, x: {x:?}"),
+        );
+        let expected = y_ref.mul(&y_ref).add(&x_ref.mul(&x_ref)).sqrt();
+        assert_within_ulps(
+            &expected,
+            y.cordic_hypot(x),
+            $ulps,
+            format_args!("hypot y: {y:?}, x: {x:?}"),
+        );
+    }};
+}
+
+#[test]
+fn cordic_exhaustive() {
+    let pi = &Ref::pi(64);
+    for i in i8::MIN..=i8::MAX {
+        check_cos_sin!(fi8(i), fi8, pi, 1.0);
+    }
+    for i in i16::MIN..=i16::MAX {
+        check_cos_sin!(fi16(i), fi16, pi, 1.0);
+    }
+}
+
+#[test]
+fn cordic_polar_exhaustive() {
+    let pi = &Ref::pi(64);
+    for i in i8::MIN..=i8::MAX {
+        for j in i8::MIN..=i8::MAX {
+            check_polar!(fi8(i), fi8(j), fi8, pi, 1.0);
+        }
+    }
+}
+
+#[test]
+fn cordic_fuzz() {
+    let pi = &Ref::pi(320);
+    let mut rng = StarRng::new(0);
+    for _ in 0..1000 {
+        check_cos_sin!(fi16::rand(&mut rng), fi16, pi, 1.0);
+        check_polar!(fi16::rand(&mut rng), fi16::rand(&mut rng), fi16, pi, 1.0);
+        check_cos_sin!(fi32::rand(&mut rng), fi32, pi, 1.0);
+        check_polar!(fi32::rand(&mut rng), fi32::rand(&mut rng), fi32, pi, 1.0);
+        check_cos_sin!(fi64::rand(&mut rng), fi64, pi, 1.0);
+        check_polar!(fi64::rand(&mut rng), fi64::rand(&mut rng), fi64, pi, 1.0);
+        check_cos_sin!(fi128::rand(&mut rng), fi128, pi, 1.0);
+        check_polar!(fi128::rand(&mut rng), fi128::rand(&mut rng), fi128, pi, 1.0);
+    }
+}
+
+#[test]
+fn cordic_special_values() {
+    assert_eq!(fi32::ZERO.cordic_cos_sin(), (fi32::ONE, fi32::ZERO));
+    assert_eq!(fi32::ZERO.cordic_atan2(fi32::ONE), fi32::ZERO);
+    assert_eq!(fi32::ZERO.cordic_atan2(fi32::NEG_ONE), fi32::ONE);
+    assert_eq!(fi32::ONE.cordic_atan2(fi32::ZERO), fi32!(0.5));
+    assert_eq!(fi32::NEG_ONE.cordic_atan2(fi32::ZERO), fi32!(-0.5));
+    assert_eq!(fi32::ZERO.cordic_atan2(fi32::ZERO), fi32::ZERO);
+    assert_eq!(fi32::ZERO.cordic_hypot(fi32::ZERO), fi32::ZERO);
+    assert_eq!(fi32::ONE.cordic_hypot(fi32::ONE), fi32::ONE);
+    assert_eq!(fi32!(0.6).cordic_hypot(fi32!(0.8)), fi32::ONE);
+}
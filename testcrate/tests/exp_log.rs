@@ -0,0 +1,178 @@
+use common::{assert_within_ulps, reference::Ref};
+use fracints::prelude::*;
+use star_rng::StarRng;
+
+/// Checks `exp2_neg` and `log2` against the reference
+macro_rules! check_exp2_log2 {
+    ($x:expr, $ty:ident, $ln2:expr, $ulps:expr) => {{
+        let x: $ty = $x;
+        let ln2: &Ref = $ln2;
+        let fp = ln2.fp;
+        let x_ref = Ref::from_fracint(x, fp);
+        if !x.is_negative() {
+            let expected = x_ref.mul(ln2).neg().exp();
+            assert_within_ulps(
+                &expected,
+                x.exp2_neg(),
+                $ulps,
+                format_args!("exp2_neg x: {x:?}"),
+            );
+        }
+        if x > $ty::ZERO {
+            let (exponent, mantissa) = x.log2().unwrap();
+            assert!(exponent < 0);
+            assert!(!mantissa.is_negative());
+            let expected = x_ref
+                .ln()
+                .div(ln2)
+                .sub(&Ref::from_i128(exponent as i128, fp));
+            assert_within_ulps(&expected, mantissa, $ulps, format_args!("log2 x: {x:?}"));
+        }
+    }};
+}
+
+/// Checks `exp_neg` and `ln_1p` against the reference, `fi256` has looser
+/// bounds than the other types
+fn check_exp_ln<F: Fracint>(x: F, fp: usize) {
+    let (exp_ulps, ln_ulps) = if F::BITS == 256 {
+        (16.0, 256.0)
+    } else {
+        (1.0, 1.0)
+    };
+    let x_ref = Ref::from_fracint(x, fp);
+    if !x.is_negative() {
+        let expected = x_ref.neg().exp();
+        assert_within_ulps(
+            &expected,
+            x.exp_neg(),
+            exp_ulps,
+            format_args!("exp_neg x: {x:?}"),
+        );
+    }
+    if x > F::MIN {
+        let expected = Ref::from_i128(1, fp).add(&x_ref).ln();
+        assert_within_ulps(
+            &expected,
+            x.ln_1p(),
+            ln_ulps,
+            format_args!("ln_1p x: {x:?}"),
+        );
+    }
+}
+
+#[test]
+fn exp_ln_exhaustive() {
+    for i in i8::MIN..=i8::MAX {
+        check_exp_ln(fi8(i), 64);
+    }
+    for i in i16::MIN..=i16::MAX {
+        check_exp_ln(fi16(i), 64);
+    }
+    for i in u8::MIN..=u8::MAX {
+        check_exp_ln(fu8(i), 64);
+    }
+    for i in u16::MIN..=u16::MAX {
+        check_exp_ln(fu16(i), 64);
+    }
+}
+
+#[test]
+fn exp_ln_fuzz() {
+    let mut rng = StarRng::new(0);
+    for _ in 0..1000 {
+        check_exp_ln(fi32::rand(&mut rng), 320);
+        check_exp_ln(fi64::rand(&mut rng), 320);
+        check_exp_ln(fi128::rand(&mut rng), 320);
+        check_exp_ln(fi256::rand(&mut rng), 320);
+        check_exp_ln(fu32::rand(&mut rng), 320);
+        check_exp_ln(fu64::rand(&mut rng), 320);
+        check_exp_ln(fu128::rand(&mut rng), 320);
+    }
+    for x in [fi32::NEG_ONE, fi32::ZERO, fi32::ULP, fi32::ONE] {
+        check_exp_ln(x, 320);
+    }
+    for x in [fi128::NEG_ONE, fi128::ZERO, fi128::ULP, fi128::ONE] {
+        check_exp_ln(x, 320);
+    }
+    for x in [fi256::NEG_ONE, fi256::ZERO, fi256::ULP, fi256::ONE] {
+        check_exp_ln(x, 320);
+    }
+    // `ln_1p` needs the most terms near where it saturates
+    for _ in 0..1000 {
+        let x = fi256::rand(&mut rng) >> 6;
+        check_exp_ln(x.wrapping_sub(fi256!(0.6)), 320);
+    }
+    for x in [fu128::ZERO, fu128::ULP, fu128::ONE] {
+        check_exp_ln(x, 320);
+    }
+}
+
+#[test]
+fn exp_ln_special_values() {
+    assert_eq!(fi32::ZERO.exp_neg(), fi32::ONE);
+    assert_eq!(fi32::NEG_ONE.exp_neg(), fi32::ONE);
+    assert_eq!(fi32!(0.5).exp_neg(), fi32!(0.60653065971263342360));
+    assert_eq!(fi32::ZERO.ln_1p(), fi32::ZERO);
+    assert_eq!(fi32!(0.5).ln_1p(), fi32!(0.40546510810816438198));
+    assert_eq!(fi32!(-0.5).ln_1p(), fi32!(-0.69314718055994530942));
+    assert_eq!(fi32!(-0.7).ln_1p(), fi32::NEG_ONE);
+    assert_eq!(fi32::NEG_ONE.ln_1p(), fi32::NEG_ONE);
+    assert_eq!(fi32::MIN.ln_1p(), fi32::NEG_ONE);
+    assert_eq!(fu32::ZERO.exp_neg(), fu32::ONE);
+    assert_eq!(fu32!(0.5).exp_neg(), fu32!(0.60653065971263342360));
+    assert_eq!(fu32::ZERO.ln_1p(), fu32::ZERO);
+    assert_eq!(fu32!(0.5).ln_1p(), fu32!(0.40546510810816438198));
+}
+
+#[test]
+fn exp2_log2_exhaustive() {
+    let ln2 = &Ref::ln2(64);
+    for i in i8::MIN..=i8::MAX {
+        check_exp2_log2!(fi8(i), fi8, ln2, 1.0);
+    }
+    for i in i16::MIN..=i16::MAX {
+        check_exp2_log2!(fi16(i), fi16, ln2, 1.0);
+    }
+}
+
+#[test]
+fn exp2_log2_fuzz() {
+    let ln2 = &Ref::ln2(320);
+    let mut rng = StarRng::new(0);
+    for _ in 0..1000 {
+        check_exp2_log2!(fi32::rand(&mut rng), fi32, ln2, 1.0);
+        check_exp2_log2!(fi64::rand(&mut rng), fi64, ln2, 1.0);
+        check_exp2_log2!(fi128::rand(&mut rng), fi128, ln2, 1.0);
+    }
+    for i in 0..31 {
+        check_exp2_log2!(fi32::ULP << i, fi32, ln2, 1.0);
+        check_exp2_log2!(fi32::ONE >> i, fi32, ln2, 1.0);
+    }
+    for i in 0..63 {
+        check_exp2_log2!(fi64::ULP << i, fi64, ln2, 1.0);
+        check_exp2_log2!(fi64::ONE >> i, fi64, ln2, 1.0);
+    }
+    for i in 0..127 {
+        check_exp2_log2!(fi128::ULP << i, fi128, ln2, 1.0);
+        check_exp2_log2!(fi128::ONE >> i, fi128, ln2, 1.0);
+    }
+}
+
+#[test]
+fn exp2_log2_special_values() {
+    assert_eq!(fi32::ZERO.exp2_neg(), fi32::ONE);
+    assert_eq!(fi32::NEG_ONE.exp2_neg(), fi32::ONE);
+    assert_eq!(fi32!(0.5).exp2_neg(), fi32!(0.70710678118654752440));
+    assert_eq!(fi32::ZERO.log2(), None);
+    assert_eq!(fi32::NEG_ONE.log2(), None);
+    assert_eq!(fi32!(0.5).log2(), Some((-1, fi32::ZERO)));
+    assert_eq!(fi32!(0.25).log2(), Some((-2, fi32::ZERO)));
+    assert_eq!(fi32::ULP.log2(), Some((-31, fi32::ZERO)));
+    assert_eq!(fi8::ULP.log2(), Some((-7, fi8::ZERO)));
+    assert_eq!(fi128::ULP.log2(), Some((-127, fi128::ZERO)));
+    assert_eq!(fi8::ONE.log2(), Some((-1, fi8(126))));
+    assert_eq!(
+        fi32!(0.75).log2(),
+        Some((-1, fi32!(0.58496250072115618145)))
+    );
+}
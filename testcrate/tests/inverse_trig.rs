@@ -0,0 +1,81 @@
+use common::{assert_within_ulps, reference::Ref};
+use fracints::prelude::*;
+use star_rng::StarRng;
+
+/// Checks `atan`, `asin`, and `acos` against the reference
+macro_rules! check_inverse_trig {
+    ($x:expr, $ty:ident, $pi:expr, $ulps:expr) => {{
+        let x: $ty = $x;
+        let pi: &Ref = $pi;
+        let fp = pi.fp;
+        let x_ref = Ref::from_fracint(x, fp);
+        let one = Ref::from_i128(1, fp);
+        let cathetus = one.sub(&x_ref.mul(&x_ref)).sqrt();
+        let asin = Ref::atan2(&x_ref, &cathetus, pi).div(pi);
+        let mut half = one.clone();
+        half.awi.ashr_(1).unwrap();
+        let checks = [
+            ("atan", x_ref.atan().div(pi), x.atan()),
+            ("acos", half.sub(&asin), x.acos()),
+            ("asin", asin, x.asin()),
+        ];
+        for (name, expected, actual) in checks {
+            assert_within_ulps(&expected, actual, $ulps, format_args!("{name} x: {x:?}"));
+        }
+    }};
+}
+
+#[test]
+fn inverse_trig_exhaustive() {
+    let pi = &Ref::pi(64);
+    for i in (i8::MIN + 2)..i8::MAX {
+        check_inverse_trig!(fi8(i), fi8, pi, 1.0);
+    }
+    for i in (i16::MIN + 2)..i16::MAX {
+        check_inverse_trig!(fi16(i), fi16, pi, 1.0);
+    }
+}
+
+#[test]
+fn inverse_trig_fuzz() {
+    let pi = &Ref::pi(320);
+    let mut rng = StarRng::new(0);
+    for _ in 0..1000 {
+        check_inverse_trig!(fi32::rand(&mut rng), fi32, pi, 1.0);
+        check_inverse_trig!(fi64::rand(&mut rng), fi64, pi, 1.0);
+        check_inverse_trig!(fi128::rand(&mut rng), fi128, pi, 1.0);
+    }
+    // near the ends where `asin` and `acos` are steepest
+    for i in 1..64i32 {
+        check_inverse_trig!(fi32::ONE.wrapping_sub(fi32(i)), fi32, pi, 1.0);
+        check_inverse_trig!(fi32::NEG_ONE.wrapping_add(fi32(i)), fi32, pi, 1.0);
+        check_inverse_trig!(fi64::ONE.wrapping_sub(fi64(i as i64)), fi64, pi, 1.0);
+        check_inverse_trig!(fi64::NEG_ONE.wrapping_add(fi64(i as i64)), fi64, pi, 1.0);
+        let ulps = fi128(i128::from(i));
+        check_inverse_trig!(fi128::ONE.wrapping_sub(ulps), fi128, pi, 1.0);
+        check_inverse_trig!(fi128::NEG_ONE.wrapping_add(ulps), fi128, pi, 1.0);
+    }
+    for i in 0..120 {
+        check_inverse_trig!(fi128::ONE.wrapping_sub(fi128::ULP << i), fi128, pi, 1.0);
+        check_inverse_trig!(fi128::NEG_ONE.wrapping_add(fi128::ULP << i), fi128, pi, 1.0);
+    }
+}
+
+#[test]
+fn inverse_trig_special_values() {
+    for x in [fi32::NEG_ONE, fi32::MIN] {
+        assert_eq!(x.atan(), fi32!(-0.25));
+        assert_eq!(x.asin(), fi32!(-0.5));
+        assert_eq!(x.acos(), fi32::ONE);
+    }
+    assert_eq!(fi32::ONE.atan(), fi32!(0.25));
+    assert_eq!(fi32::ONE.asin(), fi32!(0.5));
+    assert_eq!(fi32::ONE.acos(), fi32::ZERO);
+    assert_eq!(fi32::ZERO.atan(), fi32::ZERO);
+    assert_eq!(fi32::ZERO.asin(), fi32::ZERO);
+    assert_eq!(fi32::ZERO.acos(), fi32!(0.5));
+    assert_eq!(fi64!(0.5).asin(), fi64!(0.16666666666666666667));
+    assert_eq!(fi64!(0.5).acos(), fi64!(0.33333333333333333333));
+    assert_eq!(fi128::MIN.acos(), fi128::ONE);
+    assert_eq!(fi128::ONE.asin(), fi128!(0.5));
+}
@@ -0,0 +1,115 @@
+use std::{fmt, str::FromStr};
+
+use fracints::prelude::*;
+use serde::{Deserialize, Serialize};
+use star_rng::StarRng;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(
+    bound = "F: Fracint + Serialize + for<'a> Deserialize<'a>, <F as FromStr>::Err: fmt::Display"
+)]
+struct Reprs<F: Fracint> {
+    default: F,
+    #[serde(with = "fracints::serde_helpers::raw")]
+    raw: F,
+    #[serde(with = "fracints::serde_helpers::decimal")]
+    decimal: F,
+    #[serde(with = "fracints::serde_helpers::float")]
+    float: F,
+}
+
+#[test]
+fn serde_representations() {
+    let x = Reprs {
+        default: fi16!(0.5),
+        raw: fi16!(-0.25),
+        decimal: fi16!(0.123),
+        float: fi16!(0.75),
+    };
+    let s = serde_json::to_string(&x).unwrap();
+    assert_eq!(
+        s,
+        r#"{"default":16384,"raw":-8192,"decimal":"0.12299","float":0.75}"#
+    );
+    assert_eq!(serde_json::from_str::<Reprs<fi16>>(&s).unwrap(), x);
+
+    assert_eq!(
+        serde_json::to_string(&fi128::MIN).unwrap(),
+        i128::MIN.to_string()
+    );
+    assert_eq!(serde_json::to_string(&fu8::ONE).unwrap(), "255");
+    assert_eq!(serde_json::from_str::<fi8>("-128").unwrap(), fi8::MIN);
+    assert!(serde_json::from_str::<fi8>("128").is_err());
+
+    // invalid values
+    let err = |s: &str| serde_json::from_str::<Reprs<fi16>>(s).is_err();
+    assert!(err(r#"{"default":0,"raw":0,"decimal":"1.5","float":0.0}"#));
+    assert!(err(r#"{"default":0,"raw":0,"decimal":"abc","float":0.0}"#));
+    assert!(err(r#"{"default":0,"raw":0,"decimal":0,"float":0.0}"#));
+    assert!(err(r#"{"default":0,"raw":0,"decimal":"0","float":1.5}"#));
+    assert!(!err(
+        r#"{"default":0,"raw":0,"decimal":"-1.0","float":-1.0}"#
+    ));
+
+    let x = Reprs {
+        default: fi32::MIN,
+        raw: fi32::MIN,
+        decimal: fi32::MIN,
+        float: fi32::MIN,
+    };
+    let s = serde_json::to_string(&x).unwrap();
+    assert_eq!(serde_json::from_str::<Reprs<fi32>>(&s).unwrap(), Reprs {
+        default: fi32::MIN,
+        raw: fi32::MIN,
+        decimal: fi32::NEG_ONE,
+        float: fi32::NEG_ONE,
+    });
+}
+
+macro_rules! check_round_trip {
+    ($x:expr, $ty:ident) => {{
+        let x: $ty = $x;
+        let y = Reprs {
+            default: x,
+            raw: x,
+            decimal: x,
+            float: $ty::ZERO,
+        };
+        let s = serde_json::to_string(&y).unwrap();
+        assert_eq!(serde_json::from_str::<Reprs<$ty>>(&s).unwrap(), y, "{s}");
+    }};
+}
+
+#[test]
+fn serde_round_trip() {
+    // `MIN` is displayed as "-1.0" the same as `NEG_ONE`
+    for i in (i8::MIN + 1)..=i8::MAX {
+        check_round_trip!(fi8(i), fi8);
+    }
+    for i in u8::MIN..=u8::MAX {
+        check_round_trip!(fu8(i), fu8);
+    }
+    for i in (i16::MIN + 1)..=i16::MAX {
+        check_round_trip!(fi16(i), fi16);
+    }
+    let mut rng = StarRng::new(0);
+    for _ in 0..1000 {
+        check_round_trip!(fi32::rand(&mut rng), fi32);
+        check_round_trip!(fi64::rand(&mut rng), fi64);
+        check_round_trip!(fi128::rand(&mut rng), fi128);
+        check_round_trip!(fu64::rand(&mut rng), fu64);
+        check_round_trip!(fu128::rand(&mut rng), fu128);
+    }
+    // `f64` has enough precision for `fi32`
+    for _ in 0..1000 {
+        let x = fi32::rand(&mut rng);
+        let y = Reprs {
+            default: x,
+            raw: x,
+            decimal: x,
+            float: x,
+        };
+        let s = serde_json::to_string(&y).unwrap();
+        assert_eq!(serde_json::from_str::<Reprs<fi32>>(&s).unwrap(), y, "{s}");
+    }
+}
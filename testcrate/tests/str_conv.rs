@@ -1,12 +1,14 @@
 use std::str::FromStr;
 
+use common::{reference::Ref, str_reference::ref_from_str};
 use fracints::{FracintSerdeError::*, *};
+use star_rng::StarRng;
 
 #[test]
 fn from_str() {
     macro_rules! a {
         ($lhs:expr, $rhs:expr) => {
-            assert_eq!(fi8::from_str($lhs), $rhs);
+            assert_eq!(fi8::from_str($lhs).map_err(FracintSerdeError::from), $rhs);
         };
     }
     a!("", Err(Empty));
@@ -55,6 +57,389 @@ fn from_str() {
 
     assert_eq!(fi64::from_str("-1").unwrap(), fi64::NEG_ONE);
     assert_eq!(fi64!(-1), fi64::NEG_ONE);
+
+    assert_eq!(fu8::from_str("0.5"), Ok(fu8(128)));
+    assert_eq!(fu8::from_str("0.00195312"), Ok(fu8(0)));
+    assert_eq!(fu8::from_str("0.00195313"), Ok(fu8(1)));
+    assert_eq!(fu8::from_str("0.998046875"), Ok(fu8(255)));
+    assert_eq!(fu8::from_str("0.999"), Ok(fu8::ONE));
+    assert_eq!(fu8::from_str("1"), Ok(fu8::ONE));
+    assert_eq!(
+        fu8::from_str("1.01").map_err(FracintSerdeError::from),
+        Err(Overflow)
+    );
+    // rounding happens before the bounds check
+    assert_eq!(fu8::from_str("-0.001"), Ok(fu8(0)));
+    assert_eq!(
+        fu8::from_str("-0.01").map_err(FracintSerdeError::from),
+        Err(Overflow)
+    );
+    assert_eq!(fu8::from_str("-_0"), Ok(fu8(0)));
+    assert_eq!(fu8::from_str("0b0.1010101"), Ok(fu8(0b1010_1010)));
+    assert_eq!(fu64!(1), fu64::ONE);
+}
+
+#[test]
+fn array_macros() {
+    assert_eq!(fi16s![0.1, -0.25, 1e-3], [
+        fi16!(0.1),
+        fi16!(-0.25),
+        fi16!(1e-3)
+    ]);
+    assert_eq!(fu8s!["0b0.1", 1, 0x0.fe,], [fu8(128), fu8::ONE, fu8(254)]);
+    assert_eq!(fi128s![-1], [fi128::NEG_ONE]);
+    let empty: [fi32; 0] = fi32s![];
+    assert_eq!(empty, []);
+    const TABLE: [fi8; 2] = fi8s![0.5, -0.5];
+    assert_eq!(TABLE, [fi8(64), fi8(-64)]);
+    assert_eq!(fi64s![0.5], [fi64!(0.5)]);
+    assert_eq!(fu16s![0.5], [fu16!(0.5)]);
+    assert_eq!(fu32s![0.5], [fu32!(0.5)]);
+    assert_eq!(fu64s![0.5], [fu64!(0.5)]);
+    assert_eq!(fu128s![0.5], [fu128!(0.5)]);
+    // `i256` has no literals, so these expand to constant expressions
+    const WIDE: [fi256; 3] = fi256s![0.5, -1, "1/3"];
+    assert_eq!(WIDE, [
+        fi256::from(fi128!(0.5)),
+        fi256::NEG_ONE,
+        "1/3".parse().unwrap()
+    ]);
+    assert_eq!(fi256!(-0.75), fi256::from(fi128!(-0.75)));
+    assert_eq!(fi256!(1e-70), "1e-70".parse().unwrap());
+}
+
+#[test]
+fn lut() {
+    let table: [fi16; 4] = fracint_lut!(fi16, x, 4);
+    assert_eq!(table, fi16s![0, 0.25, 0.5, 0.75]);
+    let table = fracint_lut!(fu8, 1 - x, 4);
+    assert_eq!(table, [fu8::ONE, fu8!(0.75), fu8!(0.5), fu8!(0.25)]);
+    let table = fracint_lut!(fi32, sin, 100);
+    for (i, y) in table.into_iter().enumerate() {
+        let expected = Ref::from_i128(i as i128, 256)
+            .div_int(100)
+            .cos_sin()
+            .1
+            .to_fracint::<fi32>();
+        assert_eq!(y, expected);
+    }
+    let table = fracint_lut!(fi64, cos(tau * x), 360);
+    for (i, y) in table.into_iter().enumerate() {
+        let expected = Ref::from_i128(i as i128 * 2, 256)
+            .mul(&Ref::pi(256))
+            .div_int(360)
+            .cos_sin()
+            .0
+            .to_fracint::<fi64>();
+        assert_eq!(y, expected);
+    }
+    let table = fracint_lut!(fi256, -sin(x), 8);
+    for (i, y) in table.into_iter().enumerate() {
+        let expected = Ref::from_i128(i as i128, 512)
+            .div_int(8)
+            .cos_sin()
+            .1
+            .neg()
+            .to_fracint::<fi256>();
+        assert_eq!(y, expected);
+    }
+    // ties round to even
+    assert_eq!(fracint_lut!(fi8, x / 128, 2), [fi8(0), fi8(0)]);
+    assert_eq!(fracint_lut!(fi8, 3 * x / 128, 2), [fi8(0), fi8(2)]);
+}
+
+#[test]
+fn const_expressions() {
+    assert_eq!(fracint_const!(fi8, 0.5), fi8!(0.5));
+    assert_eq!(fracint_const!(fi8, 1 / 2), fi8!(0.5));
+    assert_eq!(fracint_const!(fi8, -1), fi8::NEG_ONE);
+    assert_eq!(fracint_const!(fi8, 1), fi8::ONE);
+    assert_eq!(fracint_const!(fu8, 1), fu8::ONE);
+    assert_eq!(fracint_const!(fu8, 1 - 1), fu8::ZERO);
+    assert_eq!(fracint_const!(fu8, -(1 - 1)), fu8::ZERO);
+    // ties round to even like the parser
+    assert_eq!(fracint_const!(fi8, 3 / 256), fi8!(0.01171875));
+    assert_eq!(fracint_const!(fi8, 3 / 256), fi8(2));
+    assert_eq!(fracint_const!(fi8, 0x10 / 0b1000_0000), fi8(16));
+    assert_eq!(
+        fracint_const!(fi32, 1_000_000 / 3_000_000),
+        fi32!(0.333333333333333)
+    );
+    assert_eq!(fracint_const!(fi64, 1e-3 * 10E2 - 0.25), fi64!(0.75));
+    assert_eq!(fracint_const!(fi64, (1 + 2) * 3 / 10 - 8 / 10), fi64!(0.1));
+    assert_eq!(fracint_const!(fi64, 2 - 3 - -1.5), fi64!(0.5));
+    assert_eq!(fracint_const!(fi64, 2 / 4 / 2), fi64!(0.25));
+    assert_eq!(
+        fracint_const!(fi64, 0.123456789e-5),
+        fi64!(0.00000123456789)
+    );
+    assert_eq!(
+        fracint_const!(fi128, 0.636619772367581343075535053490057448137_8),
+        fi128!(0.636619772367581343075535053490057448137_8)
+    );
+
+    assert_eq!(
+        fracint_const!(fi128, pi / 4),
+        fi128!(0.785398163397448309615660845819875721049292)
+    );
+    assert_eq!(
+        fracint_const!(fu128, tau / 8),
+        fu128!(0.785398163397448309615660845819875721049292)
+    );
+    assert_eq!(
+        fracint_const!(fi128, e / 4),
+        fi128!(0.679570457114761308840071867838165624439)
+    );
+    assert_eq!(
+        fracint_const!(fi128, sqrt(0.5)),
+        fi128!(0.707106781186547524400844362104849039284835)
+    );
+    assert_eq!(
+        fracint_const!(fi128, cos(0.1)),
+        fi128!(0.995004165278025766095561987803870294838)
+    );
+    assert_eq!(
+        fracint_const!(fi128, sin(-100)),
+        fi128!(0.506365641109758793656557610459785432065)
+    );
+    assert_eq!(
+        fracint_const!(fi128, tan(0.5)),
+        fi128!(0.546302489843790513255179465780285383297)
+    );
+    assert_eq!(fracint_const!(fi128, atan(1) / pi), fi128!(0.25));
+    assert_eq!(fracint_const!(fi128, atan2(-1, -1) / pi), fi128!(-0.75));
+    assert_eq!(
+        fracint_const!(fi128, asin(0.5) / pi),
+        fracint_const!(fi128, 1 / 6)
+    );
+    assert_eq!(
+        fracint_const!(fi128, acos(0.5) / pi),
+        fracint_const!(fi128, 1 / 3)
+    );
+    assert_eq!(
+        fracint_const!(fi128, exp(-1)),
+        fi128!(0.367879441171442321595523770161460867445811)
+    );
+    assert_eq!(
+        fracint_const!(fi128, ln(2)),
+        fi128!(0.693147180559945309417232121458176568075500)
+    );
+    assert_eq!(fracint_const!(fi128, log2(8) / 4), fi128!(0.75));
+    assert_eq!(fracint_const!(fi128, log10(1000) / 4), fi128!(0.75));
+    assert_eq!(fracint_const!(fi128, -abs(-0.5)), fi128!(-0.5));
+    assert_eq!(fracint_const!(fi256, 1 / 3), "1/3".parse().unwrap());
+    assert_eq!(fracint_const!(fi256, -1), fi256::NEG_ONE);
+    assert_eq!(fracint_const!(fi256, 1), fi256::ONE);
+    assert_eq!(fracint_const!(fi256, 0), fi256::ZERO);
+    assert_eq!(fracint_const!(fi256, -1e-70), fi256!(-1e-70));
+    assert_eq!(
+        fracint_const!(fi256, sqrt(0.5)),
+        Ref::from_i128(1, 512).div_int(2).sqrt().to_fracint()
+    );
+
+    // the macro works through `macro_rules` fragments
+    macro_rules! forward {
+        ($ty:ident, $e:expr) => {
+            fracint_const!($ty, $e)
+        };
+    }
+    assert_eq!(forward!(fi16, 1 / 4), fi16!(0.25));
+    assert_eq!(forward!(fi16, sqrt(1 / 4)), fi16!(0.5));
+}
+
+#[test]
+fn parse_errors() {
+    macro_rules! a {
+        ($ty:ident, $s:expr, $kind:expr, $position:expr) => {
+            let err = $ty::from_str($s).unwrap_err();
+            assert_eq!((err.kind(), err.position()), ($kind, $position), "{}", $s);
+            assert_eq!(err.bound(), None);
+        };
+    }
+    a!(fi8, "", Empty, 0);
+    a!(fi8, "__", Empty, 0);
+    a!(fi8, "-", EmptyInteger, 1);
+    a!(fi8, "-0x", EmptyInteger, 3);
+    a!(fi8, "-0x.1", EmptyInteger, 3);
+    a!(fi8, "0.1x", InvalidCharInFraction, 3);
+    a!(fi8, "0b012", InvalidCharInInteger, 4);
+    a!(fu8, "-0.", EmptyFraction, 3);
+    a!(fu8, "0.1_2e", EmptyExponent, 6);
+    a!(fu8, "0.1e-_", EmptyExponent, 5);
+    a!(fu8, "0.1e-2-", InvalidCharInExponent, 6);
+    a!(fu8, "0.1e2_-", InvalidCharInExponent, 6);
+    a!(fu8, "0.1e-2a", InvalidCharInExponent, 6);
+    assert_eq!(
+        fi8::from_str("0.1e-2a").unwrap_err().to_string(),
+        "The exponent was empty, or there was a char in the exponent that was not valid at byte 6"
+    );
+
+    macro_rules! overflow {
+        ($ty:ident, $s:expr, $position:expr, $bound:ident) => {
+            let err = $ty::from_str($s).unwrap_err();
+            assert_eq!(err, Overflow);
+            assert_eq!(err.position(), $position);
+            assert_eq!(
+                err.is_negative(),
+                Some(FracintBound::$bound == FracintBound::Lower)
+            );
+            assert_eq!(err.bound(), Some(FracintBound::$bound));
+        };
+    }
+    overflow!(fi8, "1.5", 0, Upper);
+    overflow!(fi8, "-1.5", 1, Lower);
+    overflow!(fi8, "-0x1.8", 3, Lower);
+    overflow!(fi8, "1.01", 0, Upper);
+    overflow!(fi8, "11e-1", 0, Upper);
+    overflow!(fi128, "1e99999999999999999999999", 0, Upper);
+    overflow!(fu8, "1.01", 0, Upper);
+    overflow!(fu8, "-0.01", 0, Lower);
+    overflow!(fu8, "-1.01", 1, Lower);
+    assert_eq!(
+        fu8::from_str("-0.01").unwrap_err().to_string(),
+        "The numeric value was not in range, the lower bound was exceeded"
+    );
+    // exponents saturate
+    assert_eq!(fi8::from_str("1e-99999999999999999999999"), Ok(fi8::ZERO));
+    assert_eq!(fi8::from_str("0e99999999999999999999999"), Ok(fi8::ZERO));
+
+    assert_eq!(
+        FracintSerdeError::from(fi16::from_str("0.a").unwrap_err()),
+        InvalidCharInFraction
+    );
+}
+
+#[test]
+fn macros() {
+    assert_eq!(fi8!("0b0.1"), fi8(64));
+    assert_eq!(fi8!("-0o0.4"), fi8(-64));
+    assert_eq!(fi8!(r"0x0.8"), fi8(64));
+    assert_eq!(fi8!(r#"0x0.8"#), fi8(64));
+    assert_eq!(fi8!(0x0.fe), fi8::MAX);
+    assert_eq!(fi8!(0b1p - 1), fi8(64));
+    assert_eq!(fi8!(0b1P - 1), fi8(64));
+    assert_eq!(fi16!(5E-1), fi16!(0.5));
+    assert_eq!(fu16!("0x0.08P1"), fu16(1 << 15));
+    assert_eq!(fi32!(-1_000e-3), fi32::NEG_ONE);
+    assert_eq!(fu128!("0.5"), fu128(1 << 127));
+    // inputs can be forwarded through `macro_rules` fragments
+    macro_rules! forward {
+        ($x:literal) => {
+            fi16!($x)
+        };
+        (- $x:expr) => {
+            fi16!(-$x)
+        };
+    }
+    assert_eq!(forward!(0.5), fi16!(0.5));
+    assert_eq!(forward!("0x0.8"), fi16!(0.5));
+    assert_eq!(forward!(-0.5), fi16!(-0.5));
+}
+
+#[test]
+fn ratios_and_percentages() {
+    assert_eq!(fi8::from_str("1/2"), Ok(fi8(64)));
+    assert_eq!(fi8::from_str("-1/2"), Ok(fi8(-64)));
+    assert_eq!(fi8::from_str("-0/2"), Ok(fi8(0)));
+    assert_eq!(fi8::from_str("2/2"), Ok(fi8::ONE));
+    assert_eq!(fi8::from_str("-255/256"), Ok(fi8::NEG_ONE));
+    assert_eq!(fi8::from_str("0x10/0b1000_0000"), Ok(fi8(16)));
+    assert_eq!(fi8::from_str("0o1/0x_2"), Ok(fi8(64)));
+    // ties round to even
+    assert_eq!(fi8::from_str("3/256"), Ok(fi8(2)));
+    assert_eq!(fi8::from_str("1/256"), Ok(fi8(0)));
+    assert_eq!(
+        fu128::from_str(
+            "340282366920938463463374607431768211455/340282366920938463463374607431768211455"
+        ),
+        Ok(fu128::ONE)
+    );
+    assert_eq!(fu128::from_str("1/3"), Ok(fu128(u128::MAX / 3)));
+    assert_eq!(fi128::from_str("1/3"), Ok(fi128(i128::MAX / 3 + 1)));
+    assert_eq!(
+        fi256::from_str("-1/3"),
+        Ok(-fi256(
+            i256::MAX
+                .wrapping_div(3i128.into())
+                .wrapping_add(1i128.into())
+        ))
+    );
+    assert_eq!(fu8::from_str("-0/3"), Ok(fu8(0)));
+    assert_eq!(fu8::from_str("-1/1024"), Ok(fu8(0)));
+
+    assert_eq!(fi8::from_str("50%"), Ok(fi8(64)));
+    assert_eq!(fi8::from_str("-100%"), Ok(fi8::NEG_ONE));
+    assert_eq!(fu16::from_str("0.5e1%"), Ok(fu16!(0.05)));
+    assert_eq!(fu16::from_str("1_2.5%"), Ok(fu16!(0.125)));
+
+    macro_rules! a {
+        ($ty:ident, $s:expr, $kind:expr, $position:expr) => {
+            let err = $ty::from_str($s).unwrap_err();
+            assert_eq!((err.kind(), err.position()), ($kind, $position), "{}", $s);
+        };
+    }
+    a!(fi8, "1/", EmptyInteger, 2);
+    a!(fi8, "1/0x", EmptyInteger, 4);
+    a!(fi8, "/2", EmptyInteger, 0);
+    a!(fi8, "1/2.", InvalidCharInInteger, 3);
+    a!(fi8, "1/-2", InvalidCharInInteger, 2);
+    a!(fi8, "0.5/2", InvalidCharInFraction, 3);
+    a!(fi8, "1e1/2", InvalidCharInExponent, 3);
+    a!(fi8, "3/2", Overflow, 0);
+    a!(fi8, "-1/0", Overflow, 1);
+    a!(fi8, "0/0", Overflow, 0);
+    a!(
+        fi8,
+        "1/1_000_000_000_000_000_000_000_000_000_000_000_000_000",
+        Overflow,
+        0
+    );
+    a!(fu8, "-1/3", Overflow, 0);
+    a!(fi8, "%", InvalidCharInInteger, 0);
+    a!(fi8, "-%", EmptyInteger, 1);
+    a!(fi8, "1%%", InvalidCharInInteger, 1);
+    a!(fi8, "1%2", InvalidCharInInteger, 1);
+    a!(fi8, "0x1%", InvalidCharInInteger, 3);
+    a!(fi8, "0x1.8%", InvalidCharInFraction, 5);
+    a!(fi8, "0x1p1%", InvalidCharInExponent, 5);
+    a!(fi8, "1/3%", InvalidCharInInteger, 3);
+    a!(fi8, "101%", Overflow, 0);
+
+    // compare against exact integer arithmetic
+    for a in -300i64..=300 {
+        for b in 1i64..=300 {
+            let s = format!("{a}/{b}");
+            let (quo, rem) = ((a.abs() << 15) / b, (a.abs() << 15) % b);
+            let round_up = ((2 * rem) > b) || (((2 * rem) == b) && ((quo & 1) != 0));
+            let mag = quo + (round_up as i64);
+            let res = fi16::from_str(&s);
+            if mag > (1 << 15) {
+                assert_eq!(res.unwrap_err(), Overflow);
+            } else if mag == (1 << 15) {
+                let one = if a < 0 { fi16::NEG_ONE } else { fi16::ONE };
+                assert_eq!(res, Ok(one), "{s}");
+            } else {
+                assert_eq!(res, Ok(fi16((a.signum() * mag) as i16)), "{s}");
+            }
+        }
+    }
+    // a percentage is the same as an exponent of -2
+    let mut rng = StarRng::new(0);
+    for _ in 0..10_000 {
+        let s = rand_str(&mut rng, 40);
+        if s.contains(['e', 'E', 'p', 'P', 'x', 'o', 'b']) {
+            continue
+        }
+        macro_rules! check {
+            ($($ty:ident)*) => {$(
+                // the errors can differ for an empty integer part
+                let res = $ty::from_str(&format!("{s}%")).ok();
+                let expected = $ty::from_str(&format!("{s}e-2")).ok();
+                assert_eq!(res, expected, "{}", s);
+            )*};
+        }
+        check!(fi8 fi32 fi128 fi256 fu16 fu64);
+    }
 }
 
 #[test]
@@ -70,14 +455,417 @@ fn to_str() {
     a!(fi8::MIN, "-1.0");
     a!(fi8(1), "0.008");
     a!(fi8(2), "0.016");
+    // exact ties round to odd
+    a!(fi8(8), "0.063");
+    a!(fi8(24), "0.187");
 
     a!(fi16(1), "0.00003");
     a!(fi32(1), "0.0000000005");
     a!(fi64(1), "0.0000000000000000001");
     a!(fi128(1), "0.000000000000000000000000000000000000006");
+    a!(fi256::ULP, format!("0.{}2", "0".repeat(76)));
+    a!(
+        fi256::from(fi128(1)),
+        "0.00000000000000000000000000000000000000587747175411143753984368268611122838909"
+    );
+
+    a!(fu8::ZERO, "0.0");
+    a!(fu8::ONE, "1.0");
+    a!(fu8(1), "0.004");
+    a!(fu8(128), "0.5");
+    a!(fu16(1), "0.00002");
 
     a!(
         fi128(108315241484954818046902227470560947936),
         "0.636619772367581343075535053490057448135"
     );
 }
+
+#[test]
+fn str_buf() {
+    assert_eq!(fi8::MIN.to_str_buf().as_str(), "-1.0");
+    assert_eq!(&*fi128::NEG_ONE.to_str_buf(), "-1.0");
+    assert_eq!(&*fi8(-127).to_str_buf(), "-1.0");
+    assert_eq!(&*fi8(-126).to_str_buf(), "-0.984");
+    assert_eq!(&*fu8::ONE.to_str_buf(), "1.0");
+    assert_eq!(&*fu8(254).to_str_buf(), "0.992");
+    assert_eq!(
+        &*fi128(-i128::MAX + 1).to_str_buf(),
+        "-0.999999999999999999999999999999999999988"
+    );
+    assert_eq!(
+        &*fu128(u128::MAX - 1).to_str_buf(),
+        "0.999999999999999999999999999999999999994"
+    );
+    assert_eq!(fi16::from_ascii(b"-0.5"), Ok(fi16(-16384)));
+    assert_eq!(fu16::from_ascii(b"0x0.8"), Ok(fu16(32768)));
+    assert_eq!(
+        fi16::from_ascii(b"\xff").map_err(FracintSerdeError::from),
+        Err(InvalidCharInInteger)
+    );
+    assert_eq!(
+        fi16::from_ascii(b"0.1e1-1").map_err(FracintSerdeError::from),
+        Err(InvalidCharInExponent)
+    );
+    // digits far past the precision only matter for breaking ties
+    assert_eq!(
+        fi8::from_ascii(b"0.0039062500000000000000000000000"),
+        Ok(fi8(0))
+    );
+    assert_eq!(
+        fi8::from_ascii(b"0.0039062500000000000000000000001"),
+        Ok(fi8(1))
+    );
+    assert_eq!(
+        fi8::from_ascii(b"0.0117187499999999999999999999999"),
+        Ok(fi8(1))
+    );
+    assert_eq!(
+        fi8::from_ascii(b"0.0117187500000000000000000000000"),
+        Ok(fi8(2))
+    );
+    assert_eq!(fi8::from_ascii(b"1e-99999999999"), Ok(fi8(0)));
+    assert_eq!(
+        fi8::from_ascii(b"1e99999999999").map_err(FracintSerdeError::from),
+        Err(Overflow)
+    );
+    assert_eq!(fi8::from_ascii(b"0e99999999999"), Ok(fi8(0)));
+
+    for i in i8::MIN..=i8::MAX {
+        let x = fi8(i);
+        assert_eq!(x.to_str_buf().as_str(), x.to_string());
+        if x != fi8::MIN {
+            assert_eq!(fi8::from_ascii(x.to_str_buf().as_bytes()), Ok(x));
+        }
+    }
+    for i in u16::MIN..=u16::MAX {
+        let x = fu16(i);
+        assert_eq!(fu16::from_ascii(x.to_str_buf().as_bytes()), Ok(x));
+    }
+    let mut rng = StarRng::new(0);
+    for _ in 0..1000 {
+        macro_rules! round_trip {
+            ($($ty:ident)*) => {$(
+                let x = $ty::rand(&mut rng);
+                if x != $ty::MIN {
+                    assert_eq!($ty::from_ascii(x.to_str_buf().as_bytes()), Ok(x));
+                }
+            )*};
+        }
+        round_trip!(fi16 fi32 fi64 fi128 fi256 fu32 fu64 fu128);
+    }
+}
+
+/// Generates strings that are mostly valid with some random corruption
+fn rand_str(rng: &mut StarRng, max_fraction: u32) -> String {
+    let mut s = String::new();
+    if rng.next_bool() {
+        s.push('-');
+    }
+    let prefix = (rng.next_u32() % 4) as usize;
+    s.push_str(["", "0b", "0o", "0x"][prefix]);
+    let digits: &[u8] = [
+        &b"0123456789"[..],
+        b"01",
+        b"01234567",
+        b"0123456789abcdefABCDEF",
+    ][prefix];
+    let push_digits = |rng: &mut StarRng, s: &mut String, max: u32| {
+        for _ in 0..(rng.next_u32() % max) {
+            let c = if rng.next_u32().is_multiple_of(8) {
+                b'_'
+            } else if rng.next_u32().is_multiple_of(3) {
+                b'0'
+            } else {
+                digits[(rng.next_u32() as usize) % digits.len()]
+            };
+            s.push(char::from(c));
+        }
+    };
+    push_digits(rng, &mut s, 4);
+    if rng.next_bool() {
+        s.push('.');
+        push_digits(rng, &mut s, max_fraction);
+    }
+    if rng.next_bool() {
+        let c = if (prefix == 3) || rng.next_bool() {
+            'p'
+        } else {
+            'e'
+        };
+        s.push(if rng.next_bool() {
+            c.to_ascii_uppercase()
+        } else {
+            c
+        });
+        if rng.next_bool() {
+            s.push('-');
+        }
+        push_digits(rng, &mut s, 3);
+    }
+    if rng.next_u32().is_multiple_of(16) {
+        let chars = b"0123456789abcdefABCDEF_.-oxb";
+        let i = (rng.next_u32() as usize) % (s.len() + 1);
+        s.insert(
+            i,
+            char::from(chars[(rng.next_u32() as usize) % chars.len()]),
+        );
+    }
+    s
+}
+
+#[test]
+fn from_str_differential() {
+    let mut rng = StarRng::new(0);
+    for i in 0..30_000 {
+        let s = rand_str(&mut rng, if (i % 2) == 0 { 8 } else { 60 });
+        // the reference allocates proportionally to the exponent
+        if let Some(i) = s.rfind(['e', 'E', 'p', 'P'])
+            && ((s.len() - i) > 6)
+        {
+            continue
+        }
+        // the reference only accepted lowercase exponent chars
+        let ref_s = if s.contains("0x") {
+            s.replace('P', "p")
+        } else {
+            s.replace('E', "e").replace('P', "p")
+        };
+        macro_rules! check {
+            ($($ty:ident)*) => {$(
+                let res = $ty::from_str(&s).map_err(FracintSerdeError::from);
+                let expected = ref_from_str::<$ty>(&ref_s);
+                // the reference did not have a special error for a '-' in the
+                // middle of the exponent
+                if !((res == Err(InvalidCharInExponent)) && (expected == Err(Overflow))) {
+                    assert_eq!(res, expected, "{} {}", stringify!($ty), s);
+                }
+            )*};
+        }
+        check!(fi8 fi16 fi32 fi64 fi128 fi256 fu8 fu16 fu32 fu64 fu128);
+    }
+}
+
+#[test]
+fn display_formatting() {
+    assert_eq!(format!("{:.3}", fi16!(0.5)), "0.500");
+    assert_eq!(format!("{:.0}", fi16!(0.5)), "0");
+    assert_eq!(format!("{:.0}", fi16!(0.75)), "1");
+    assert_eq!(format!("{:.0}", fi16!(-0.75)), "-1");
+    assert_eq!(format!("{:.2}", fi8(8)), "0.06");
+    assert_eq!(format!("{:.3}", fi8(8)), "0.062");
+    assert_eq!(format!("{:.1}", fi8(-1)), "-0.0");
+    assert_eq!(format!("{:.3}", fi16(32766)), "1.000");
+    assert_eq!(format!("{:.3}", fi16::ONE), "1.000");
+    assert_eq!(format!("{:.3}", fi16::NEG_ONE), "-1.000");
+    assert_eq!(format!("{:.3}", fi16::MIN), "-1.000");
+    assert_eq!(format!("{:.3}", fu16::ONE), "1.000");
+    assert_eq!(format!("{:.0}", fu16::ONE), "1");
+    assert_eq!(format!("{:.3}", fu16::ZERO), "0.000");
+    assert_eq!(format!("{:.10}", fi8(1)), "0.0078125000");
+    assert_eq!(
+        format!("{:.130}", fi8(1)),
+        format!("0.0078125{}", "0".repeat(123))
+    );
+    // exactly `2^-128`
+    assert_eq!(
+        format!("{:.130}", fu128(1)),
+        "0.00000000000000000000000000000000000000293873587705571876992184134305561419454666389\
+         19302188037718792656960431486368179321289062500"
+    );
+
+    assert_eq!(format!("{:>8}", fi16!(0.5)), "     0.5");
+    assert_eq!(format!("{:<8}|", fi16!(0.5)), "0.5     |");
+    assert_eq!(format!("{:^8}", fi16!(0.5)), "  0.5   ");
+    assert_eq!(format!("{:*>8.2}", fi16!(-0.5)), "***-0.50");
+    assert_eq!(format!("{:+}", fi16!(0.5)), "+0.5");
+    assert_eq!(format!("{:+}", fi16!(-0.5)), "-0.5");
+    assert_eq!(format!("{:+.1}", fu8::ONE), "+1.0");
+    assert_eq!(format!("{:08.3}", fi16!(-0.5)), "-000.500");
+    assert_eq!(format!("{:+08}", fi16!(0.5)), "+00000.5");
+    assert_eq!(format!("{:2}", fi16!(0.5)), "0.5");
+    assert_eq!(format!("{:>6}|{:<6}", fi8::ONE, fi8::ZERO), "   1.0|0.0   ");
+    // `Debug` is unaffected
+    assert_eq!(format!("{:.1?}", fi16!(0.25)), "fi16(0.25)");
+
+    // compare against the exact formatting of `f64`
+    for i in (i16::MIN + 1)..i16::MAX {
+        let x = fi16(i);
+        if x == fi16::NEG_ONE {
+            continue
+        }
+        for precision in 0..8 {
+            assert_eq!(
+                format!("{x:.precision$}"),
+                format!("{:.precision$}", x.to_f64())
+            );
+        }
+    }
+    let mut rng = StarRng::new(0);
+    for _ in 0..10_000 {
+        let x = fi32::rand(&mut rng);
+        let y = fu32::rand(&mut rng);
+        for precision in [0, 1, 5, 9, 10, 11, 40] {
+            if (x != fi32::ONE) && (x > fi32::NEG_ONE) {
+                assert_eq!(
+                    format!("{x:.precision$}"),
+                    format!("{:.precision$}", x.to_f64())
+                );
+            }
+            if y != fu32::ONE {
+                assert_eq!(
+                    format!("{y:.precision$}"),
+                    format!("{:.precision$}", y.to_f64())
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn shortest() {
+    assert_eq!(fi8(1).to_string_shortest(), "0.01");
+    assert_eq!(fi8::ZERO.to_string_shortest(), "0.0");
+    assert_eq!(fi8::ONE.to_string_shortest(), "1.0");
+    assert_eq!(fi8::NEG_ONE.to_string_shortest(), "-1.0");
+    assert_eq!(fi16!(0.5).to_string_shortest(), "0.5");
+    assert_eq!(fi16!(0.1).to_string_shortest(), "0.1");
+    assert_eq!(fi64!(0.1).to_string_shortest(), "0.1");
+    assert_eq!(fi128!(-0.123).to_string_shortest(), "-0.123");
+    assert_eq!(
+        fi64::ONE.saturating_div_int(3).to_string_shortest(),
+        "0.3333333333333333333"
+    );
+    assert_eq!(format!("{:#}", fu8(1)), "0.004");
+    assert_eq!(format!("{:>+#8}", fi8(1)), "   +0.01");
+    assert_eq!(&*fi32!(0.7).to_str_buf_shortest(), "0.7");
+
+    // exhaustively check that no shorter decimal parses to the same value
+    for i in (i8::MIN + 1)..=i8::MAX {
+        let x = fi8(i);
+        let s = x.to_string_shortest();
+        assert_eq!(fi8::from_str(&s), Ok(x));
+        let len = s.trim_start_matches('-').len() - 2;
+        for digits in 1..len {
+            for j in 0..10i32.pow(digits as u32) {
+                let t = format!("{}0.{j:0digits$}", if i < 0 { "-" } else { "" });
+                assert_ne!(fi8::from_str(&t), Ok(x), "{t} {s}");
+            }
+        }
+    }
+    for i in 0..=u16::MAX {
+        let x = fu16(i);
+        let s = x.to_string_shortest();
+        assert_eq!(fu16::from_str(&s), Ok(x));
+        assert!(s.len() <= x.to_string().len());
+    }
+    let mut rng = StarRng::new(0);
+    for _ in 0..1000 {
+        macro_rules! round_trip {
+            ($($ty:ident)*) => {$(
+                let x = $ty::rand(&mut rng);
+                if x != $ty::MIN {
+                    let s = x.to_string_shortest();
+                    assert_eq!($ty::from_str(&s), Ok(x));
+                    assert!(s.len() <= x.to_string().len());
+                }
+            )*};
+        }
+        round_trip!(fi16 fi32 fi64 fi128 fi256 fu32 fu64 fu128);
+    }
+}
+
+#[test]
+fn radix_and_exp_formatting() {
+    assert_eq!(format!("{:x}", fi8(1)), "0x0.02");
+    assert_eq!(format!("{:X}", fi8::MAX), "0x0.FE");
+    assert_eq!(format!("{:x}", fi8(-64)), "-0x0.8");
+    assert_eq!(format!("{:x}", fi8::MIN), "-0x1.0");
+    assert_eq!(format!("{:x}", fi8::ZERO), "0x0.0");
+    assert_eq!(format!("{:b}", fi8(1)), "0b0.0000001");
+    assert_eq!(format!("{:o}", fi8(1)), "0o0.004");
+    assert_eq!(format!("{:o}", fu8::MAX), "0o0.776");
+    assert_eq!(
+        format!("{:x}", fu128::MAX),
+        format!("0x0.{}", "f".repeat(32))
+    );
+    assert_eq!(format!("{:.4x}", fi8(1)), "0x0.0200");
+    assert_eq!(format!("{:.1x}", fi8(1)), "0x0.0");
+    assert_eq!(format!("{:.1b}", fu8(192)), "0b1.0");
+    assert_eq!(format!("{:+010b}", fu8(128)), "+0b00000.1");
+    assert_eq!(format!("{:>8x}|", fi8(-64)), "  -0x0.8|");
+    assert_eq!(fi16!(0.5).to_string_radix(16), "0x0.8");
+    assert_eq!(fi8(1).to_string_radix(10), "0.008");
+    assert_eq!(fu8(128).to_string_radix(3), "0.111112");
+
+    assert_eq!(format!("{:e}", fi16!(0.5)), "5e-1");
+    assert_eq!(format!("{:e}", fi16!(0.125)), "1.25e-1");
+    assert_eq!(format!("{:E}", fi16!(-0.5)), "-5E-1");
+    assert_eq!(format!("{:e}", fi8::ONE), "1e0");
+    assert_eq!(format!("{:e}", fi8::MIN), "-1e0");
+    assert_eq!(format!("{:e}", fu8::ZERO), "0e0");
+    assert_eq!(format!("{:.2e}", fu8::ZERO), "0.00e0");
+    assert_eq!(format!("{:.2e}", fi16!(0.5)), "5.00e-1");
+    assert_eq!(format!("{:.1e}", fi16!(0.996)), "1.0e0");
+    assert_eq!(format!("{:e}", fu128(1)), "3e-39");
+    assert_eq!(format!("{:+10.1e}", fi16!(0.25)), "   +2.5e-1");
+
+    // compare against the exact formatting of `f64`
+    for i in (i16::MIN + 1)..i16::MAX {
+        let x = fi16(i);
+        if x == fi16::NEG_ONE {
+            continue
+        }
+        for precision in 0..8 {
+            assert_eq!(
+                format!("{x:.precision$e}"),
+                format!("{:.precision$e}", x.to_f64())
+            );
+        }
+    }
+
+    for i in (i8::MIN + 1)..=i8::MAX {
+        let x = fi8(i);
+        for s in [
+            format!("{x:b}"),
+            format!("{x:o}"),
+            format!("{x:x}"),
+            format!("{x:X}"),
+            format!("{x:e}"),
+            format!("{x:E}"),
+        ] {
+            assert_eq!(fi8::from_str(&s), Ok(x), "{s}");
+        }
+    }
+    for i in 0..=u16::MAX {
+        let x = fu16(i);
+        for s in [
+            format!("{x:b}"),
+            format!("{x:o}"),
+            format!("{x:X}"),
+            format!("{x:E}"),
+        ] {
+            assert_eq!(fu16::from_str(&s), Ok(x), "{s}");
+        }
+    }
+    let mut rng = StarRng::new(0);
+    for _ in 0..1000 {
+        macro_rules! round_trip {
+            ($($ty:ident)*) => {$(
+                let x = $ty::rand(&mut rng);
+                if x != $ty::MIN {
+                    for s in [
+                        format!("{x:b}"),
+                        format!("{x:o}"),
+                        format!("{x:x}"),
+                        format!("{x:e}"),
+                    ] {
+                        assert_eq!($ty::from_str(&s), Ok(x), "{s}");
+                    }
+                    assert_eq!(x.to_string_radix(16), format!("{x:x}"));
+                }
+            )*};
+        }
+        round_trip!(fi16 fi32 fi64 fi128 fi256 fu32 fu64 fu128);
+    }
+}
@@ -0,0 +1,82 @@
+use common::{assert_within_ulps, reference::Ref};
+use fracints::prelude::*;
+use star_rng::StarRng;
+
+/// Checks `cos_taudiv4_taylor`, `sin_taudiv4_taylor`, and `cos_sin_pi_taylor`
+/// against the reference
+macro_rules! check_taylor {
+    ($x:expr, $ty:ident, $pi:expr, $ulps:expr) => {{
+        let x: $ty = $x;
+        let pi: &Ref = $pi;
+        let fp = pi.fp;
+        let theta = Ref::from_fracint(x, fp).mul(pi).div_int(2);
+        let (cos_taudiv4, sin_taudiv4) = theta.cos_sin();
+        // double angle formulas
+        let cos_pi = cos_taudiv4
+            .mul(&cos_taudiv4)
+            .sub(&sin_taudiv4.mul(&sin_taudiv4));
+        let sin_pi = cos_taudiv4
+            .mul(&sin_taudiv4)
+            .add(&cos_taudiv4.mul(&sin_taudiv4));
+        let (cos, sin) = x.cos_sin_pi_taylor();
+        let checks = [
+            ("cos_taudiv4", cos_taudiv4, x.cos_taudiv4_taylor()),
+            ("sin_taudiv4", sin_taudiv4, x.sin_taudiv4_taylor()),
+            ("cos_pi", cos_pi, cos),
+            ("sin_pi", sin_pi, sin),
+        ];
+        for (name, expected, actual) in checks {
+            assert_within_ulps(&expected, actual, $ulps, format_args!("{name} x: {x:?}"));
+        }
+    }};
+}
+
+#[test]
+fn taylor_exhaustive() {
+    let pi = &Ref::pi(64);
+    for i in i8::MIN..=i8::MAX {
+        check_taylor!(fi8(i), fi8, pi, 0.5);
+    }
+    for i in i16::MIN..=i16::MAX {
+        check_taylor!(fi16(i), fi16, pi, 0.5);
+    }
+}
+
+#[test]
+fn taylor_fuzz() {
+    let pi = &Ref::pi(320);
+    let mut rng = StarRng::new(0);
+    for _ in 0..1000 {
+        check_taylor!(fi32::rand(&mut rng), fi32, pi, 0.5);
+        check_taylor!(fi64::rand(&mut rng), fi64, pi, 0.5);
+        check_taylor!(fi128::rand(&mut rng), fi128, pi, 0.5);
+    }
+    for x in [fi32::MIN, fi32::NEG_ONE, fi32::ZERO, fi32::ULP, fi32::ONE] {
+        check_taylor!(x, fi32, pi, 0.5);
+    }
+    for x in [
+        fi128::MIN,
+        fi128::NEG_ONE,
+        fi128::ZERO,
+        fi128::ULP,
+        fi128::ONE,
+    ] {
+        check_taylor!(x, fi128, pi, 0.5);
+    }
+}
+
+#[test]
+fn taylor_special_values() {
+    assert_eq!(fi32::ZERO.cos_sin_pi_taylor(), (fi32::ONE, fi32::ZERO));
+    assert_eq!(fi32!(0.5).cos_sin_pi_taylor(), (fi32::ZERO, fi32::ONE));
+    assert_eq!(fi32!(-0.5).cos_sin_pi_taylor(), (fi32::ZERO, fi32::NEG_ONE));
+    assert_eq!(fi32::MIN.cos_sin_pi_taylor(), (fi32::NEG_ONE, fi32::ZERO));
+    assert_eq!(fi32::MIN.cos_taudiv4_taylor(), fi32::ZERO);
+    assert_eq!(fi32::MIN.sin_taudiv4_taylor(), fi32::NEG_ONE);
+    assert_eq!(fi32::ONE.sin_taudiv4_taylor(), fi32::ONE);
+    assert_eq!(
+        fi64!(0.25).cos_sin_pi_taylor(),
+        (fi64!(0.7071067811865475244), fi64!(0.7071067811865475244))
+    );
+    assert_eq!(fi16!(1.0).cos_taudiv4_taylor(), fi16(2));
+}
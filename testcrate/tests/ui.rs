@@ -0,0 +1,7 @@
+//! Checks the `compile_error!`s of the macros and the tokens they point at
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,5 @@
+use fracints::prelude::*;
+
+fn main() {
+    let _: [fi16; 5] = fi16s![0.1, 0.5 x, -0.25, 2.0, "\t"];
+}
@@ -0,0 +1,8 @@
+use fracints::prelude::*;
+
+fn main() {
+    let _ = fi8!(0.5 x 1);
+    let _ = fi32!(0.1e-2a);
+    let _ = fu16!(-0.5);
+    let _ = fi8!(1.5);
+}
@@ -0,0 +1,6 @@
+use fracints::prelude::*;
+
+fn main() {
+    let _ = fi16!("0.\x35");
+    let _ = fi16!("0x0.8p-1x");
+}
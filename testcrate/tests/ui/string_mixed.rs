@@ -0,0 +1,6 @@
+use fracints::prelude::*;
+
+fn main() {
+    let _ = fi32!(-"0.5");
+    let _ = fi32!("0.5" 1);
+}